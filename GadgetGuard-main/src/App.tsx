@@ -2,23 +2,30 @@ import { useCallback, useEffect, useMemo, useState } from "react";
 import { WalletMultiButton } from "@solana/wallet-adapter-react-ui";
 import { useAnchorWallet, useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import {
   BN,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
   SystemProgram,
   claimStatusLabel,
   deriveClaimPda,
+  derivePayoutSchedulePda,
   deriveMemberPda,
+  derivePolicyPda,
   derivePoolAuthorityPda,
   derivePoolPda,
+  deriveShareMintPda,
+  deriveTreasuryPda,
+  deriveVendorPda,
   ensureAta,
+  sha256,
   getProgram,
   toClaimVariant,
 } from "./lib/solana";
 import {
   POOL_ADDRESS,
   TEST_MINT_ADDRESS,
-  VOTE_WINDOW_SECS,
   explorerUrl,
   formatTokenAmount,
   parseUiAmount,
@@ -30,10 +37,14 @@ type TxStage = "idle" | "sending" | "confirmed" | "error";
 type ClaimRow = {
   id: bigint;
   claimant: string;
+  policy: string;
+  vendor: string | null;
+  payoutMint: string;
   claimType: string;
   requestedAmount: bigint;
   createdTs: number;
-  status: "PENDING" | "APPROVED" | "REJECTED" | "PAID";
+  voteDeadline: number;
+  status: "PENDING" | "APPROVED" | "REJECTED" | "PAID" | "PAYING";
   yesVotes: number;
   noVotes: number;
   evidenceUri: string;
@@ -70,6 +81,8 @@ export default function App() {
   const [claimAmountUi, setClaimAmountUi] = useState("20");
   const [claimType, setClaimType] = useState<"damage" | "theft" | "loss">("damage");
   const [evidenceUri, setEvidenceUri] = useState("");
+  const [deviceSerial, setDeviceSerial] = useState("");
+  const [vendorWallet, setVendorWallet] = useState("");
 
   const [txStage, setTxStage] = useState<TxStage>("idle");
   const [txSig, setTxSig] = useState<string | null>(null);
@@ -129,9 +142,13 @@ export default function App() {
         return {
           id: bnToBigInt(claim.claimId),
           claimant: claim.claimant.toBase58(),
+          policy: claim.policy.toBase58(),
+          vendor: claim.vendor ? claim.vendor.toBase58() : null,
+          payoutMint: claim.payoutMint.toBase58(),
           claimType: Object.keys(claim.claimType)[0]?.toUpperCase() || "DAMAGE",
           requestedAmount: bnToBigInt(claim.requestedAmount),
           createdTs: Number(claim.createdTs),
+          voteDeadline: Number(claim.voteDeadline),
           status: claimStatusLabel(claim.status),
           yesVotes: Number(claim.yesVotes),
           noVotes: Number(claim.noVotes),
@@ -170,8 +187,14 @@ export default function App() {
     const program = getProgram(wallet.wallet, wallet.publicKey);
     const [memberPda] = deriveMemberPda(poolAddress, wallet.publicKey);
 
+    // Gated pools need the wallet's Merkle proof, published alongside the allowlist root
+    const allowlist = await fetch("/allowlist.json")
+      .then((r) => (r.ok ? r.json() : null))
+      .catch(() => null);
+    const proof: string[] = allowlist?.proofs?.[wallet.publicKey.toBase58()] ?? [];
+
     return program.methods
-      .joinPool()
+      .joinPool(proof.map((node) => Array.from(Buffer.from(node, "hex"))))
       .accounts({
         member: memberPda,
         pool: poolAddress,
@@ -189,8 +212,15 @@ export default function App() {
     const program = getProgram(wallet.wallet, wallet.publicKey);
     const [memberPda] = deriveMemberPda(poolAddress, wallet.publicKey);
 
+    const [shareMint] = deriveShareMintPda(poolAddress);
+
     const { ata: memberAta } = await ensureAta(wallet, mintAddress, wallet.publicKey, false);
     const { ata: vaultAta } = await ensureAta(wallet, mintAddress, poolAuthority, true);
+    // Shares live on a Token-2022 mint
+    const { ata: shareAta } = await ensureAta(wallet, shareMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    // The fee treasury is only required while a deposit fee is set
+    const pool = await program.account.pool.fetch(poolAddress);
+    const treasury = pool.depositFeeBps > 0 ? deriveTreasuryPda(poolAddress, mintAddress)[0] : null;
 
     return program.methods
       .deposit(new BN(amount.toString()))
@@ -199,9 +229,14 @@ export default function App() {
         pool: poolAddress,
         memberTokenAccount: memberAta,
         poolVault: vaultAta,
+        acceptedMint: mintAddress,
+        treasury,
         poolAuthority,
+        shareMint,
+        memberShareAccount: shareAta,
         user: wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
       })
       .rpc();
   };
@@ -214,8 +249,12 @@ export default function App() {
     const program = getProgram(wallet.wallet, wallet.publicKey);
     const [memberPda] = deriveMemberPda(poolAddress, wallet.publicKey);
 
+    const [shareMint] = deriveShareMintPda(poolAddress);
+
     const { ata: memberAta } = await ensureAta(wallet, mintAddress, wallet.publicKey, false);
     const { ata: vaultAta } = await ensureAta(wallet, mintAddress, poolAuthority, true);
+    // Shares live on a Token-2022 mint
+    const { ata: shareAta } = await ensureAta(wallet, shareMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
 
     return program.methods
       .withdraw(new BN(amount.toString()))
@@ -224,9 +263,13 @@ export default function App() {
         pool: poolAddress,
         memberTokenAccount: memberAta,
         poolVault: vaultAta,
+        acceptedMint: mintAddress,
         poolAuthority,
+        shareMint,
+        memberShareAccount: shareAta,
         user: wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
       })
       .rpc();
   };
@@ -241,21 +284,40 @@ export default function App() {
     if (amount <= 0n) throw new Error("Claim amount must be positive");
     if (amount > memberClaimLimit) throw new Error("Claim amount exceeds your claim limit");
 
+    if (deviceSerial.length === 0) throw new Error("Device serial is required");
+
     const program = getProgram(wallet.wallet, wallet.publicKey);
     const [memberPda] = deriveMemberPda(poolAddress, wallet.publicKey);
     const [claimPda] = deriveClaimPda(poolAddress, BigInt(claimCount));
+    const [policyPda] = await derivePolicyPda(poolAddress, wallet.publicKey, deviceSerial);
+    const [shareMint] = deriveShareMintPda(poolAddress);
     const { ata: vaultAta } = await ensureAta(wallet, mintAddress, poolAuthority, true);
+    // Shares live on a Token-2022 mint
+    const { ata: shareAta } = await ensureAta(wallet, shareMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    // Voters check the evidence against this hash, so it must be the bundle's content
+    const evidence = await fetch(evidenceUri).catch(() => null);
+    if (!evidence?.ok) throw new Error("Could not fetch the evidence bundle to hash it");
+    const evidenceHash = await sha256(new Uint8Array(await evidence.arrayBuffer()));
+    // Pay a registered repair vendor instead of the claimant, if one is named
+    const vendor = vendorWallet.length > 0 ? deriveVendorPda(poolAddress, new PublicKey(vendorWallet))[0] : null;
 
     return program.methods
-      .submitClaim(toClaimVariant(claimType), new BN(amount.toString()), evidenceUri)
+      .submitClaim(toClaimVariant(claimType), new BN(amount.toString()), evidenceUri, Array.from(evidenceHash))
       .accounts({
         claim: claimPda,
         member: memberPda,
         pool: poolAddress,
         poolVault: vaultAta,
         poolAuthority,
+        policy: policyPda,
+        vendor,
+        payoutMint: null,
+        shareMint,
+        memberShareAccount: shareAta,
         user: wallet.publicKey,
         systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
       })
       .rpc();
   };
@@ -265,6 +327,8 @@ export default function App() {
     const program = getProgram(wallet.wallet, wallet.publicKey);
     const [memberPda] = deriveMemberPda(poolAddress, wallet.publicKey);
     const [claimPda] = deriveClaimPda(poolAddress, id);
+    const [shareMint] = deriveShareMintPda(poolAddress);
+    const { ata: shareAta } = await ensureAta(wallet, shareMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
 
     return program.methods
       .voteClaim(voteYes)
@@ -272,30 +336,78 @@ export default function App() {
         claim: claimPda,
         member: memberPda,
         pool: poolAddress,
+        shareMint,
+        memberShareAccount: shareAta,
         user: wallet.publicKey,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
       })
       .rpc();
   };
 
   const finalizeClaim = async (claim: ClaimRow) => {
-    if (!wallet.publicKey || !anchorWallet || !mintAddress) throw new Error("Connect wallet first");
+    if (!wallet.publicKey || !anchorWallet) throw new Error("Connect wallet first");
     const program = getProgram(wallet.wallet, wallet.publicKey);
     const [claimPda] = deriveClaimPda(poolAddress, claim.id);
-    const claimant = new PublicKey(claim.claimant);
-
-    const { ata: vaultAta } = await ensureAta(wallet, mintAddress, poolAuthority, true);
-    const { ata: claimantAta } = await ensureAta(wallet, mintAddress, claimant, false);
+    const [memberPda] = deriveMemberPda(poolAddress, new PublicKey(claim.claimant));
 
     return program.methods
       .finalizeClaim()
       .accounts({
         claim: claimPda,
+        policy: new PublicKey(claim.policy),
+        member: memberPda,
+        pool: poolAddress,
+      })
+      .rpc();
+  };
+
+  const executePayout = async (claim: ClaimRow) => {
+    if (!wallet.publicKey || !anchorWallet) throw new Error("Connect wallet first");
+    const program = getProgram(wallet.wallet, wallet.publicKey);
+    const [claimPda] = deriveClaimPda(poolAddress, claim.id);
+    const claimant = new PublicKey(claim.claimant);
+    const [memberPda] = deriveMemberPda(poolAddress, claimant);
+    const payoutMint = new PublicKey(claim.payoutMint);
+
+    // Vendor claims are paid to the vendor's wallet
+    const vendor = claim.vendor ? new PublicKey(claim.vendor) : null;
+    const payee = vendor ? ((await program.account.vendor.fetch(vendor)).wallet as PublicKey) : claimant;
+
+    const { ata: vaultAta } = await ensureAta(wallet, payoutMint, poolAuthority, true);
+    const { ata: payeeAta } = await ensureAta(wallet, payoutMint, payee, false);
+
+    // Large claims are paid from a payout schedule created beforehand
+    const [schedulePda] = derivePayoutSchedulePda(claimPda);
+    const payoutSchedule = (await program.account.payoutSchedule.fetchNullable(schedulePda)) ? schedulePda : null;
+
+    const pool = await program.account.pool.fetch(poolAddress);
+    // The fee treasury is only required while a payout fee is set
+    const treasury = pool.payoutFeeBps > 0 ? deriveTreasuryPda(poolAddress, payoutMint)[0] : null;
+    // While premiums are charged, the payout burns one of the claimant's coverage credits
+    const coverageMint = pool.coverageMint as PublicKey;
+    const usesCredits = !pool.premiumPerMonth.isZero() && !coverageMint.equals(PublicKey.default);
+    const claimantCoverageAccount = usesCredits
+      ? getAssociatedTokenAddressSync(coverageMint, claimant, false, TOKEN_2022_PROGRAM_ID)
+      : null;
+
+    return program.methods
+      .executePayout()
+      .accounts({
+        claim: claimPda,
+        policy: new PublicKey(claim.policy),
+        member: memberPda,
         pool: poolAddress,
         poolVault: vaultAta,
-        claimantTokenAccount: claimantAta,
+        payeeTokenAccount: payeeAta,
+        payoutMint,
+        treasury,
         poolAuthority,
-        claimant,
+        vendor,
+        payoutSchedule,
+        coverageMint: usesCredits ? coverageMint : null,
+        claimantCoverageAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
+        token2022Program: usesCredits ? TOKEN_2022_PROGRAM_ID : null,
       })
       .rpc();
   };
@@ -449,7 +561,7 @@ export default function App() {
               </thead>
               <tbody>
                 {claims.map((c) => {
-                  const voteWindowOpen = Date.now() / 1000 < c.createdTs + VOTE_WINDOW_SECS;
+                  const voteWindowOpen = Date.now() / 1000 <= c.voteDeadline;
                   return (
                     <tr key={c.id.toString()}>
                       <td>#{c.id.toString()}</td>
@@ -461,6 +573,7 @@ export default function App() {
                         <button disabled={!wallet.connected || txStage === "sending" || c.status !== "PENDING"} onClick={() => runAction("Vote yes", () => voteClaim(c.id, true))}>Yes</button>
                         <button disabled={!wallet.connected || txStage === "sending" || c.status !== "PENDING"} onClick={() => runAction("Vote no", () => voteClaim(c.id, false))}>No</button>
                         <button disabled={!wallet.connected || txStage === "sending" || c.status !== "PENDING" || voteWindowOpen} onClick={() => runAction("Finalize", () => finalizeClaim(c))}>Finalize</button>
+                        <button disabled={!wallet.connected || txStage === "sending" || c.status !== "APPROVED"} onClick={() => runAction("Pay out", () => executePayout(c))}>Pay out</button>
                       </td>
                     </tr>
                   );
@@ -486,6 +599,12 @@ export default function App() {
             <input value={claimAmountUi} onChange={(e) => setClaimAmountUi(e.target.value)} />
             <small>Current limit: {formatTokenAmount(memberClaimLimit)}</small>
 
+            <label>Device Serial</label>
+            <input value={deviceSerial} onChange={(e) => setDeviceSerial(e.target.value)} placeholder="Serial of the insured device" />
+
+            <label>Repair Vendor (optional)</label>
+            <input value={vendorWallet} onChange={(e) => setVendorWallet(e.target.value)} placeholder="Registered vendor wallet" />
+
             <label>Evidence URL</label>
             <input value={evidenceUri} onChange={(e) => setEvidenceUri(e.target.value)} placeholder="https://..." />
 
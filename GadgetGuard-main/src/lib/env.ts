@@ -11,7 +11,6 @@ export const TEST_MINT_ADDRESS = import.meta.env.VITE_TEST_MINT_ADDRESS
   ? new PublicKey(import.meta.env.VITE_TEST_MINT_ADDRESS)
   : null;
 export const TEST_MINT_DECIMALS = Number(import.meta.env.VITE_TEST_MINT_DECIMALS || "6");
-export const VOTE_WINDOW_SECS = Number(import.meta.env.VITE_VOTE_WINDOW_SECS || "60");
 
 export const EXPLORER_BASE = "https://explorer.solana.com";
 
@@ -1,178 +1,3952 @@
 import type { Idl } from "@coral-xyz/anchor";
 
 export const GADGETGUARD_IDL: Idl = {
-  version: "0.1.0",
-  name: "gadgetguard",
-  instructions: [
-    {
-      name: "initializePool",
-      accounts: [
-        { name: "pool", isMut: true, isSigner: false },
-        { name: "acceptedMint", isMut: false, isSigner: false },
-        { name: "admin", isMut: true, isSigner: true },
-        { name: "systemProgram", isMut: false, isSigner: false }
-      ],
-      args: [
-        { name: "maxClaimPct", type: "u16" },
-        { name: "voteWindowSecs", type: "i64" },
-        { name: "quorum", type: "u8" },
-        { name: "approvalRatio", type: "u16" }
-      ]
-    },
-    {
-      name: "joinPool",
-      accounts: [
-        { name: "member", isMut: true, isSigner: false },
-        { name: "pool", isMut: true, isSigner: false },
-        { name: "user", isMut: true, isSigner: true },
-        { name: "systemProgram", isMut: false, isSigner: false }
-      ],
-      args: []
-    },
-    {
-      name: "deposit",
-      accounts: [
-        { name: "member", isMut: true, isSigner: false },
-        { name: "pool", isMut: true, isSigner: false },
-        { name: "memberTokenAccount", isMut: true, isSigner: false },
-        { name: "poolVault", isMut: true, isSigner: false },
-        { name: "poolAuthority", isMut: false, isSigner: false },
-        { name: "user", isMut: true, isSigner: true },
-        { name: "tokenProgram", isMut: false, isSigner: false }
-      ],
-      args: [{ name: "amount", type: "u64" }]
-    },
-    {
-      name: "withdraw",
-      accounts: [
-        { name: "member", isMut: true, isSigner: false },
-        { name: "pool", isMut: true, isSigner: false },
-        { name: "memberTokenAccount", isMut: true, isSigner: false },
-        { name: "poolVault", isMut: true, isSigner: false },
-        { name: "poolAuthority", isMut: false, isSigner: false },
-        { name: "user", isMut: true, isSigner: true },
-        { name: "tokenProgram", isMut: false, isSigner: false }
-      ],
-      args: [{ name: "amount", type: "u64" }]
-    },
-    {
-      name: "submitClaim",
-      accounts: [
-        { name: "claim", isMut: true, isSigner: false },
-        { name: "member", isMut: false, isSigner: false },
-        { name: "pool", isMut: true, isSigner: false },
-        { name: "poolVault", isMut: false, isSigner: false },
-        { name: "poolAuthority", isMut: false, isSigner: false },
-        { name: "user", isMut: true, isSigner: true },
-        { name: "systemProgram", isMut: false, isSigner: false }
-      ],
-      args: [
-        { name: "claimType", type: { defined: "ClaimType" } },
-        { name: "requestedAmount", type: "u64" },
-        { name: "evidenceUri", type: "string" }
-      ]
-    },
-    {
-      name: "voteClaim",
-      accounts: [
-        { name: "claim", isMut: true, isSigner: false },
-        { name: "member", isMut: false, isSigner: false },
-        { name: "pool", isMut: false, isSigner: false },
-        { name: "user", isMut: false, isSigner: true }
-      ],
-      args: [{ name: "voteYes", type: "bool" }]
-    },
-    {
-      name: "finalizeClaim",
-      accounts: [
-        { name: "claim", isMut: true, isSigner: false },
-        { name: "pool", isMut: true, isSigner: false },
-        { name: "poolVault", isMut: true, isSigner: false },
-        { name: "claimantTokenAccount", isMut: true, isSigner: false },
-        { name: "poolAuthority", isMut: false, isSigner: false },
-        { name: "claimant", isMut: false, isSigner: false },
-        { name: "tokenProgram", isMut: false, isSigner: false }
-      ],
-      args: []
+  "address": "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS",
+  "metadata": {
+    "name": "gadgetguard",
+    "version": "0.1.0",
+    "spec": "0.1.0",
+    "description": "Decentralized freelancer protection pool on Solana"
+  },
+  "instructions": [
+    {
+      "name": "initialize_pool",
+      "discriminator": [
+        95,
+        180,
+        10,
+        172,
+        84,
+        174,
+        232,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "max_claim_pct",
+          "type": "u16"
+        },
+        {
+          "name": "vote_window_secs",
+          "type": "i64"
+        },
+        {
+          "name": "quorum",
+          "type": "u8"
+        },
+        {
+          "name": "approval_ratio",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "configure_claim_type",
+      "discriminator": [
+        237,
+        114,
+        166,
+        14,
+        151,
+        181,
+        1,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "claim_type",
+          "type": {
+            "defined": {
+              "name": "gadgetguard::state::ClaimType"
+            }
+          }
+        },
+        {
+          "name": "rules",
+          "type": {
+            "defined": {
+              "name": "gadgetguard::state::ClaimTypeRules"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "create_share_mint",
+      "discriminator": [
+        91,
+        27,
+        147,
+        39,
+        213,
+        104,
+        78,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "share_mint",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_2022_program"
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "create_coverage_mint",
+      "discriminator": [
+        123,
+        78,
+        237,
+        60,
+        144,
+        224,
+        162,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "coverage_mint",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_2022_program"
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "join_pool",
+      "discriminator": [
+        14,
+        65,
+        62,
+        16,
+        116,
+        17,
+        195,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "discriminator": [
+        242,
+        35,
+        198,
+        137,
+        82,
+        225,
+        242,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "member_token_account",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "share_mint",
+          "writable": true
+        },
+        {
+          "name": "member_share_account",
+          "writable": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "share_token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deposit_asset",
+      "discriminator": [
+        107,
+        93,
+        89,
+        87,
+        226,
+        203,
+        154,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "asset_mint"
+        },
+        {
+          "name": "member_token_account",
+          "writable": true
+        },
+        {
+          "name": "asset_vault",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "share_mint",
+          "writable": true
+        },
+        {
+          "name": "member_share_account",
+          "writable": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "share_token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "pay_premium",
+      "discriminator": [
+        156,
+        253,
+        113,
+        97,
+        167,
+        54,
+        253,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "member_token_account",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "backstop",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "coverage_mint",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "member_coverage_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "token_2022_program",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "months",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "expire_coverage_credits",
+      "discriminator": [
+        175,
+        212,
+        63,
+        62,
+        227,
+        235,
+        133,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "member"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "coverage_mint",
+          "writable": true
+        },
+        {
+          "name": "member_coverage_account",
+          "writable": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "token_2022_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdraw",
+      "discriminator": [
+        183,
+        18,
+        70,
+        156,
+        148,
+        109,
+        161,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "member_token_account",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "share_mint",
+          "writable": true
+        },
+        {
+          "name": "member_share_account",
+          "writable": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "share_token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdraw_asset",
+      "discriminator": [
+        78,
+        193,
+        207,
+        125,
+        63,
+        193,
+        129,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "asset_mint"
+        },
+        {
+          "name": "member_token_account",
+          "writable": true
+        },
+        {
+          "name": "asset_vault",
+          "writable": true
+        },
+        {
+          "name": "pool_vault"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "share_mint",
+          "writable": true
+        },
+        {
+          "name": "member_share_account",
+          "writable": true
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "base_token_program"
+        },
+        {
+          "name": "share_token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "value",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "register_underwriter",
+      "discriminator": [
+        202,
+        178,
+        205,
+        235,
+        29,
+        30,
+        48,
+        27
+      ],
+      "accounts": [
+        {
+          "name": "underwriter",
+          "writable": true
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "underwrite",
+      "discriminator": [
+        130,
+        243,
+        71,
+        79,
+        111,
+        78,
+        186,
+        213
+      ],
+      "accounts": [
+        {
+          "name": "underwriter",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "user_token_account",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdraw_capital",
+      "discriminator": [
+        82,
+        32,
+        82,
+        118,
+        160,
+        116,
+        65,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "underwriter",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "user_token_account",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "register_device",
+      "discriminator": [
+        210,
+        151,
+        56,
+        68,
+        22,
+        158,
+        90,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "gadgetguard::state::DeviceCategory"
+            }
+          }
+        },
+        {
+          "name": "serial_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "declared_value",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "renew_policy",
+      "discriminator": [
+        218,
+        56,
+        246,
+        187,
+        84,
+        167,
+        250,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "user",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submit_claim",
+      "discriminator": [
+        163,
+        108,
+        111,
+        46,
+        220,
+        82,
+        77,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_vault"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "vendor",
+          "optional": true
+        },
+        {
+          "name": "payout_mint",
+          "optional": true
+        },
+        {
+          "name": "share_mint"
+        },
+        {
+          "name": "member_share_account"
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "share_token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "claim_type",
+          "type": {
+            "defined": {
+              "name": "gadgetguard::state::ClaimType"
+            }
+          }
+        },
+        {
+          "name": "requested_amount",
+          "type": "u64"
+        },
+        {
+          "name": "evidence_uri",
+          "type": "string"
+        },
+        {
+          "name": "evidence_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "add_evidence",
+      "discriminator": [
+        205,
+        19,
+        129,
+        228,
+        117,
+        97,
+        33,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "evidence",
+          "writable": true
+        },
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "vote_claim",
+      "discriminator": [
+        119,
+        71,
+        176,
+        255,
+        239,
+        225,
+        251,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "member"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "share_mint"
+        },
+        {
+          "name": "member_share_account"
+        },
+        {
+          "name": "user",
+          "signer": true
+        },
+        {
+          "name": "share_token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "vote_yes",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "finalize_claim",
+      "discriminator": [
+        86,
+        162,
+        202,
+        241,
+        136,
+        125,
+        52,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configure_oracle",
+      "discriminator": [
+        245,
+        58,
+        202,
+        16,
+        204,
+        36,
+        82,
+        199
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oracle",
+          "type": "pubkey"
+        },
+        {
+          "name": "decision_mode",
+          "type": {
+            "defined": {
+              "name": "gadgetguard::state::DecisionMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "oracle_decide_claim",
+      "discriminator": [
+        138,
+        128,
+        144,
+        26,
+        134,
+        194,
+        6,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "oracle",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "configure_assessors",
+      "discriminator": [
+        87,
+        13,
+        82,
+        145,
+        99,
+        4,
+        227,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "assessors",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "submit_assessment",
+      "discriminator": [
+        7,
+        162,
+        212,
+        231,
+        150,
+        246,
+        85,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "instructions"
+        }
+      ],
+      "args": [
+        {
+          "name": "assessment",
+          "type": {
+            "defined": {
+              "name": "gadgetguard::state::Assessment"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "create_committee",
+      "discriminator": [
+        216,
+        137,
+        130,
+        24,
+        113,
+        11,
+        204,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "committee",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "rotate_committee",
+      "discriminator": [
+        47,
+        172,
+        164,
+        76,
+        186,
+        126,
+        111,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "committee",
+          "writable": true
+        },
+        {
+          "name": "pool"
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "configure_committee",
+      "discriminator": [
+        179,
+        123,
+        110,
+        2,
+        248,
+        169,
+        51,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "committee"
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "active",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "attest_claim",
+      "discriminator": [
+        155,
+        101,
+        33,
+        39,
+        9,
+        31,
+        214,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "committee"
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "attester",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "create_payout_schedule",
+      "discriminator": [
+        27,
+        179,
+        58,
+        241,
+        218,
+        84,
+        171,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "payout_schedule",
+          "writable": true
+        },
+        {
+          "name": "claim"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "execute_payout",
+      "discriminator": [
+        12,
+        35,
+        52,
+        7,
+        95,
+        19,
+        169,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "payee_token_account",
+          "writable": true
+        },
+        {
+          "name": "payout_mint"
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "vendor",
+          "optional": true
+        },
+        {
+          "name": "payout_schedule",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "coverage_mint",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "claimant_coverage_account",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "token_2022_program",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "veto_claim",
+      "discriminator": [
+        126,
+        226,
+        170,
+        29,
+        224,
+        46,
+        164,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configure_payout_delay",
+      "discriminator": [
+        2,
+        48,
+        199,
+        63,
+        25,
+        41,
+        88,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "delay_secs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "configure_installments",
+      "discriminator": [
+        10,
+        140,
+        37,
+        131,
+        12,
+        84,
+        61,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u64"
+        },
+        {
+          "name": "count",
+          "type": "u8"
+        },
+        {
+          "name": "interval_secs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "release_payout",
+      "discriminator": [
+        181,
+        87,
+        198,
+        92,
+        64,
+        3,
+        24,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "payout_schedule",
+          "writable": true
+        },
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "payee_token_account",
+          "writable": true
+        },
+        {
+          "name": "payout_mint"
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "vendor",
+          "optional": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settle_shortfall",
+      "discriminator": [
+        59,
+        8,
+        46,
+        165,
+        2,
+        153,
+        108,
+        40
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "payee_token_account",
+          "writable": true
+        },
+        {
+          "name": "payout_mint"
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "vendor",
+          "optional": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "create_backstop",
+      "discriminator": [
+        66,
+        133,
+        128,
+        23,
+        165,
+        122,
+        131,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "backstop",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configure_backstop",
+      "discriminator": [
+        103,
+        64,
+        223,
+        86,
+        133,
+        175,
+        24,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "premium_share_bps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "fund_backstop",
+      "discriminator": [
+        33,
+        201,
+        149,
+        56,
+        31,
+        176,
+        21,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "pool"
+        },
+        {
+          "name": "backstop",
+          "writable": true
+        },
+        {
+          "name": "funder_token_account",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "funder",
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "draw_backstop",
+      "discriminator": [
+        101,
+        136,
+        199,
+        243,
+        189,
+        40,
+        35,
+        195
+      ],
+      "accounts": [
+        {
+          "name": "claim",
+          "writable": true
+        },
+        {
+          "name": "policy",
+          "writable": true
+        },
+        {
+          "name": "member",
+          "writable": true
+        },
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "backstop",
+          "writable": true
+        },
+        {
+          "name": "payee_token_account",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "vendor",
+          "optional": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "create_treasury",
+      "discriminator": [
+        254,
+        98,
+        217,
+        51,
+        25,
+        88,
+        140,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configure_fees",
+      "discriminator": [
+        180,
+        96,
+        224,
+        105,
+        174,
+        237,
+        193,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "deposit_fee_bps",
+          "type": "u16"
+        },
+        {
+          "name": "premium_fee_bps",
+          "type": "u16"
+        },
+        {
+          "name": "payout_fee_bps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "withdraw_fees",
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "configure_strategy",
+      "discriminator": [
+        121,
+        251,
+        51,
+        122,
+        37,
+        70,
+        251,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "strategy_program",
+          "type": "pubkey"
+        },
+        {
+          "name": "min_liquid_reserve_bps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "deploy_to_strategy",
+      "discriminator": [
+        215,
+        49,
+        61,
+        222,
+        180,
+        60,
+        9,
+        118
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "strategy_authority"
+        },
+        {
+          "name": "strategy_escrow",
+          "writable": true
+        },
+        {
+          "name": "strategy_program"
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "recall_from_strategy",
+      "discriminator": [
+        176,
+        185,
+        31,
+        174,
+        87,
+        206,
+        247,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "pool_vault",
+          "writable": true
+        },
+        {
+          "name": "accepted_mint"
+        },
+        {
+          "name": "pool_authority"
+        },
+        {
+          "name": "strategy_authority"
+        },
+        {
+          "name": "strategy_escrow",
+          "writable": true
+        },
+        {
+          "name": "strategy_program"
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "token_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "recognize_strategy_loss",
+      "discriminator": [
+        171,
+        116,
+        172,
+        235,
+        154,
+        158,
+        18,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "add_asset",
+      "discriminator": [
+        81,
+        53,
+        134,
+        142,
+        243,
+        73,
+        42,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "asset_mint"
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rate_bps",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "set_asset_rate",
+      "discriminator": [
+        11,
+        94,
+        176,
+        213,
+        222,
+        38,
+        237,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "asset_mint"
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rate_bps",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "configure_reserve",
+      "discriminator": [
+        125,
+        174,
+        86,
+        225,
+        167,
+        80,
+        149,
+        88
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "min_reserve_bps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "configure_deposit_limits",
+      "discriminator": [
+        23,
+        12,
+        155,
+        29,
+        220,
+        228,
+        190,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "min_deposit",
+          "type": "u64"
+        },
+        {
+          "name": "max_deposit_per_member",
+          "type": "u64"
+        },
+        {
+          "name": "pool_capacity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "configure_claim_vesting",
+      "discriminator": [
+        101,
+        124,
+        28,
+        163,
+        63,
+        36,
+        149,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "vesting_secs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "configure_claim_caps",
+      "discriminator": [
+        137,
+        127,
+        143,
+        170,
+        17,
+        41,
+        44,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "claim_period_secs",
+          "type": "i64"
+        },
+        {
+          "name": "lifetime_payout_cap",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "configure_allowlist",
+      "discriminator": [
+        48,
+        221,
+        194,
+        173,
+        192,
+        170,
+        62,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "configure_policy_term",
+      "discriminator": [
+        232,
+        193,
+        25,
+        96,
+        249,
+        220,
+        253,
+        187
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "term_secs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "configure_max_declared_value",
+      "discriminator": [
+        91,
+        15,
+        60,
+        254,
+        218,
+        94,
+        142,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "max_declared_value",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "configure_premiums",
+      "discriminator": [
+        245,
+        83,
+        181,
+        8,
+        205,
+        50,
+        17,
+        125
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "writable": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "premium_per_month",
+          "type": "u64"
+        },
+        {
+          "name": "min_months_for_claim",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "register_vendor",
+      "discriminator": [
+        192,
+        133,
+        122,
+        251,
+        31,
+        137,
+        113,
+        141
+      ],
+      "accounts": [
+        {
+          "name": "vendor",
+          "writable": true
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "vendor_wallet"
+        },
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "set_vendor_active",
+      "discriminator": [
+        184,
+        213,
+        172,
+        120,
+        106,
+        90,
+        97,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "vendor",
+          "writable": true
+        },
+        {
+          "name": "pool"
+        },
+        {
+          "name": "admin",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "active",
+          "type": "bool"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "gadgetguard::state::Claim",
+      "discriminator": [
+        155,
+        70,
+        22,
+        176,
+        123,
+        215,
+        246,
+        102
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Committee",
+      "discriminator": [
+        96,
+        136,
+        210,
+        244,
+        137,
+        110,
+        178,
+        104
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Evidence",
+      "discriminator": [
+        160,
+        73,
+        93,
+        206,
+        99,
+        242,
+        62,
+        92
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Member",
+      "discriminator": [
+        54,
+        19,
+        162,
+        21,
+        29,
+        166,
+        17,
+        198
+      ]
+    },
+    {
+      "name": "gadgetguard::state::PayoutSchedule",
+      "discriminator": [
+        174,
+        41,
+        8,
+        152,
+        235,
+        107,
+        94,
+        222
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Policy",
+      "discriminator": [
+        222,
+        135,
+        7,
+        163,
+        235,
+        177,
+        33,
+        68
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Pool",
+      "discriminator": [
+        241,
+        154,
+        109,
+        4,
+        17,
+        177,
+        109,
+        188
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Underwriter",
+      "discriminator": [
+        10,
+        234,
+        225,
+        56,
+        134,
+        71,
+        236,
+        232
+      ]
+    },
+    {
+      "name": "gadgetguard::state::Vendor",
+      "discriminator": [
+        87,
+        248,
+        121,
+        239,
+        24,
+        112,
+        197,
+        200
+      ]
     }
   ],
-  accounts: [
-    {
-      name: "pool",
-      type: {
-        kind: "struct",
-        fields: [
-          { name: "admin", type: "publicKey" },
-          { name: "acceptedMint", type: "publicKey" },
-          { name: "totalDeposits", type: "u64" },
-          { name: "totalPaidOut", type: "u64" },
-          { name: "claimCount", type: "u64" },
-          { name: "memberCount", type: "u64" },
-          { name: "maxClaimPct", type: "u16" },
-          { name: "voteWindowSecs", type: "i64" },
-          { name: "quorum", type: "u8" },
-          { name: "approvalRatio", type: "u16" },
-          { name: "bump", type: "u8" }
-        ]
-      }
-    },
-    {
-      name: "member",
-      type: {
-        kind: "struct",
-        fields: [
-          { name: "pool", type: "publicKey" },
-          { name: "member", type: "publicKey" },
-          { name: "depositedAmount", type: "u64" },
-          { name: "claimLimit", type: "u64" },
-          { name: "lastClaimTs", type: "i64" },
-          { name: "active", type: "bool" },
-          { name: "bump", type: "u8" }
-        ]
-      }
-    },
-    {
-      name: "claim",
-      type: {
-        kind: "struct",
-        fields: [
-          { name: "pool", type: "publicKey" },
-          { name: "claimId", type: "u64" },
-          { name: "claimant", type: "publicKey" },
-          { name: "claimType", type: { defined: "ClaimType" } },
-          { name: "requestedAmount", type: "u64" },
-          { name: "evidenceUri", type: "string" },
-          { name: "createdTs", type: "i64" },
-          { name: "status", type: { defined: "ClaimStatus" } },
-          { name: "yesVotes", type: "u8" },
-          { name: "noVotes", type: "u8" },
-          { name: "voters", type: { vec: "publicKey" } },
-          { name: "bump", type: "u8" }
+  "types": [
+    {
+      "name": "gadgetguard::state::AcceptedAsset",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "rate_bps",
+            "type": "u32"
+          }
         ]
       }
-    }
-  ],
-  types: [
+    },
+    {
+      "name": "gadgetguard::state::Assessment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "claim_id",
+            "type": "u64"
+          },
+          {
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "name": "approved_amount",
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::Claim",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "claim_id",
+            "type": "u64"
+          },
+          {
+            "name": "claimant",
+            "type": "pubkey"
+          },
+          {
+            "name": "policy",
+            "type": "pubkey"
+          },
+          {
+            "name": "claim_type",
+            "type": {
+              "defined": {
+                "name": "gadgetguard::state::ClaimType"
+              }
+            }
+          },
+          {
+            "name": "requested_amount",
+            "type": "u64"
+          },
+          {
+            "name": "approved_amount",
+            "type": "u64"
+          },
+          {
+            "name": "paid_amount",
+            "type": "u64"
+          },
+          {
+            "name": "shortfall_amount",
+            "type": "u64"
+          },
+          {
+            "name": "evidence_uri",
+            "type": "string"
+          },
+          {
+            "name": "evidence_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "evidence_count",
+            "type": "u8"
+          },
+          {
+            "name": "vendor",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "payout_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "created_ts",
+            "type": "i64"
+          },
+          {
+            "name": "created_slot",
+            "type": "u64"
+          },
+          {
+            "name": "vote_deadline",
+            "type": "i64"
+          },
+          {
+            "name": "quorum",
+            "type": "u8"
+          },
+          {
+            "name": "approval_ratio",
+            "type": "u16"
+          },
+          {
+            "name": "approved_ts",
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "gadgetguard::state::ClaimStatus"
+              }
+            }
+          },
+          {
+            "name": "yes_votes",
+            "type": "u8"
+          },
+          {
+            "name": "no_votes",
+            "type": "u8"
+          },
+          {
+            "name": "yes_weight",
+            "type": "u64"
+          },
+          {
+            "name": "no_weight",
+            "type": "u64"
+          },
+          {
+            "name": "voters",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "oracle_decision",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "oracle_amount",
+            "type": "u64"
+          },
+          {
+            "name": "committee_epoch",
+            "type": "u32"
+          },
+          {
+            "name": "approve_attestations",
+            "type": "u16"
+          },
+          {
+            "name": "reject_attestations",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::ClaimStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Approved"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Paid"
+          },
+          {
+            "name": "Paying"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::ClaimType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Damage"
+          },
+          {
+            "name": "Theft"
+          },
+          {
+            "name": "Loss"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::ClaimTypeRules",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "max_payout_bps",
+            "type": "u16"
+          },
+          {
+            "name": "vote_window_secs",
+            "type": "i64"
+          },
+          {
+            "name": "quorum",
+            "type": "u8"
+          },
+          {
+            "name": "approval_ratio",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::Committee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "epoch",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::DecisionMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "VotesOnly"
+          },
+          {
+            "name": "OracleOnly"
+          },
+          {
+            "name": "OracleWithOverride"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::DeviceCategory",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Laptop"
+          },
+          {
+            "name": "Phone"
+          },
+          {
+            "name": "Tablet"
+          },
+          {
+            "name": "Camera"
+          },
+          {
+            "name": "Other"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::Evidence",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "claim",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "added_ts",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::Member",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "deposited_amount",
+            "type": "u64"
+          },
+          {
+            "name": "claim_limit",
+            "type": "u64"
+          },
+          {
+            "name": "last_claim_ts",
+            "type": "i64"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "coverage_until",
+            "type": "i64"
+          },
+          {
+            "name": "months_paid_streak",
+            "type": "u32"
+          },
+          {
+            "name": "premiums_paid",
+            "type": "u64"
+          },
+          {
+            "name": "vesting_amount",
+            "type": "u64"
+          },
+          {
+            "name": "vesting_start_ts",
+            "type": "i64"
+          },
+          {
+            "name": "vesting_end_ts",
+            "type": "i64"
+          },
+          {
+            "name": "total_claimed",
+            "type": "u64"
+          },
+          {
+            "name": "claims_paid",
+            "type": "u32"
+          },
+          {
+            "name": "recent_payouts",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "gadgetguard::state::PayoutRecord"
+                  }
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "pending_claims",
+            "type": "u64"
+          },
+          {
+            "name": "last_deposit_slot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::PayoutRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ts",
+            "type": "i64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::PayoutSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "claim",
+            "type": "pubkey"
+          },
+          {
+            "name": "total_amount",
+            "type": "u64"
+          },
+          {
+            "name": "released_amount",
+            "type": "u64"
+          },
+          {
+            "name": "tranche_count",
+            "type": "u8"
+          },
+          {
+            "name": "tranches_released",
+            "type": "u8"
+          },
+          {
+            "name": "start_ts",
+            "type": "i64"
+          },
+          {
+            "name": "interval_secs",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::Policy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "category",
+            "type": {
+              "defined": {
+                "name": "gadgetguard::state::DeviceCategory"
+              }
+            }
+          },
+          {
+            "name": "serial_hash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "declared_value",
+            "type": "u64"
+          },
+          {
+            "name": "coverage_start",
+            "type": "i64"
+          },
+          {
+            "name": "coverage_end",
+            "type": "i64"
+          },
+          {
+            "name": "total_paid",
+            "type": "u64"
+          },
+          {
+            "name": "pending_amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gadgetguard::state::Pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "accepted_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "base_decimals",
+            "type": "u8"
+          },
+          {
+            "name": "assets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "gadgetguard::state::AcceptedAsset"
+                }
+              }
+            }
+          },
+          {
+            "name": "total_deposits",
+            "type": "u64"
+          },
+          {
+            "name": "total_paid_out",
+            "type": "u64"
+          },
+          {
+            "name": "total_shortfall",
+            "type": "u64"
+          },
+          {
+            "name": "claim_count",
+            "type": "u64"
+          },
+          {
+            "name": "member_count",
+            "type": "u64"
+          },
+          {
+            "name": "max_claim_pct",
+            "type": "u16"
+          },
+          {
+            "name": "vote_window_secs",
+            "type": "i64"
+          },
+          {
+            "name": "quorum",
+            "type": "u8"
+          },
+          {
+            "name": "approval_ratio",
+            "type": "u16"
+          },
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          },
+          {
+            "name": "decision_mode",
+            "type": {
+              "defined": {
+                "name": "gadgetguard::state::DecisionMode"
+              }
+            }
+          },
+          {
+            "name": "assessors",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "committee_active",
+            "type": "bool"
+          },
+          {
+            "name": "claim_type_rules",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "gadgetguard::state::ClaimTypeRules"
+                  }
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "payout_delay_secs",
+            "type": "i64"
+          },
+          {
+            "name": "policy_term_secs",
+            "type": "i64"
+          },
+          {
+            "name": "max_declared_value",
+            "type": "u64"
+          },
+          {
+            "name": "premium_per_month",
+            "type": "u64"
+          },
+          {
+            "name": "min_months_for_claim",
+            "type": "u8"
+          },
+          {
+            "name": "total_premiums",
+            "type": "u64"
+          },
+          {
+            "name": "coverage_mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "underwriter_capital",
+            "type": "u64"
+          },
+          {
+            "name": "premium_income",
+            "type": "u64"
+          },
+          {
+            "name": "underwriter_shares",
+            "type": "u64"
+          },
+          {
+            "name": "outstanding_exposure",
+            "type": "u64"
+          },
+          {
+            "name": "installment_threshold",
+            "type": "u64"
+          },
+          {
+            "name": "installment_count",
+            "type": "u8"
+          },
+          {
+            "name": "installment_interval_secs",
+            "type": "i64"
+          },
+          {
+            "name": "deposit_fee_bps",
+            "type": "u16"
+          },
+          {
+            "name": "premium_fee_bps",
+            "type": "u16"
+          },
+          {
+            "name": "payout_fee_bps",
+            "type": "u16"
+          },
+          {
+            "name": "fees_collected",
+            "type": "u64"
+          },
+          {
+            "name": "strategy_program",
+            "type": "pubkey"
+          },
+          {
+            "name": "min_liquid_reserve_bps",
+            "type": "u16"
+          },
+          {
+            "name": "strategy_deployed",
+            "type": "u64"
+          },
+          {
+            "name": "backstop_premium_bps",
+            "type": "u16"
+          },
+          {
+            "name": "total_backstop_draws",
+            "type": "u64"
+          },
+          {
+            "name": "min_reserve_bps",
+            "type": "u16"
+          },
+          {
+            "name": "min_deposit",
+            "type": "u64"
+          },
+          {
+            "name": "max_deposit_per_member",
+            "type": "u64"
+          },
+          {
+            "name": "pool_capacity",
+            "type": "u64"
+          },
+          {
+            "name": "claim_vesting_secs",
+            "type": "i64"
+          },
+          {
+            "name": "claim_period_secs",
+            "type": "i64"
+          },
+          {
+            "name": "lifetime_payout_cap",
+            "type": "u64"
+          },
+          {
+            "name": "allowlist_root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
-      name: "ClaimType",
-      type: {
-        kind: "enum",
-        variants: [{ name: "damage" }, { name: "theft" }, { name: "loss" }]
+      "name": "gadgetguard::state::Underwriter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "contributed_amount",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
       }
     },
     {
-      name: "ClaimStatus",
-      type: {
-        kind: "enum",
-        variants: [
-          { name: "pending" },
-          { name: "approved" },
-          { name: "rejected" },
-          { name: "paid" }
+      "name": "gadgetguard::state::Vendor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
         ]
       }
     }
   ],
-  events: [],
-  errors: []
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InsufficientPoolFunds",
+      "msg": "Insufficient funds in pool vault"
+    },
+    {
+      "code": 6001,
+      "name": "ClaimExceedsLimit",
+      "msg": "Requested claim amount exceeds member's claim limit"
+    },
+    {
+      "code": 6002,
+      "name": "AlreadyVoted",
+      "msg": "Member has already voted on this claim"
+    },
+    {
+      "code": 6003,
+      "name": "VoteWindowNotExpired",
+      "msg": "Vote window has not expired yet"
+    },
+    {
+      "code": 6004,
+      "name": "VoteWindowExpired",
+      "msg": "Vote window has expired"
+    },
+    {
+      "code": 6005,
+      "name": "ClaimNotPending",
+      "msg": "Claim is not in pending status"
+    },
+    {
+      "code": 6006,
+      "name": "MemberNotActive",
+      "msg": "Member is not active in the pool"
+    },
+    {
+      "code": 6007,
+      "name": "InsufficientMemberDeposit",
+      "msg": "Insufficient member deposit for withdrawal"
+    },
+    {
+      "code": 6008,
+      "name": "PendingClaimsExist",
+      "msg": "Member has pending claims, cannot withdraw"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidClaimType",
+      "msg": "Invalid claim type"
+    },
+    {
+      "code": 6010,
+      "name": "EvidenceUriTooLong",
+      "msg": "Evidence URI too long (max 200 chars)"
+    },
+    {
+      "code": 6011,
+      "name": "ArithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6012,
+      "name": "QuorumNotReached",
+      "msg": "Quorum not reached"
+    },
+    {
+      "code": 6013,
+      "name": "MaxVotersReached",
+      "msg": "Maximum voters reached for this claim"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidGovernanceConfig",
+      "msg": "Invalid governance configuration"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidClaimant",
+      "msg": "Claimant account does not match claim"
+    },
+    {
+      "code": 6016,
+      "name": "Unauthorized",
+      "msg": "Signer is not the pool admin"
+    },
+    {
+      "code": 6017,
+      "name": "ClaimNotApproved",
+      "msg": "Claim is not in approved status"
+    },
+    {
+      "code": 6018,
+      "name": "PayoutScheduleRequired",
+      "msg": "Claim must be paid in installments, payout schedule account required"
+    },
+    {
+      "code": 6019,
+      "name": "NoTrancheDue",
+      "msg": "No installment tranche is due yet"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidVendor",
+      "msg": "Vendor account does not match claim"
+    },
+    {
+      "code": 6021,
+      "name": "VendorNotActive",
+      "msg": "Vendor is not active"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPayee",
+      "msg": "Payee token account does not match claim"
+    },
+    {
+      "code": 6023,
+      "name": "NoShortfall",
+      "msg": "Claim has no outstanding shortfall"
+    },
+    {
+      "code": 6024,
+      "name": "PoolInShortfall",
+      "msg": "Pool owes unpaid claims, withdrawals are paused"
+    },
+    {
+      "code": 6025,
+      "name": "ClaimNotPaying",
+      "msg": "Claim is not being paid out"
+    },
+    {
+      "code": 6026,
+      "name": "PayoutTimelockActive",
+      "msg": "Payout timelock has not expired yet"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidOracle",
+      "msg": "Signer is not the pool oracle"
+    },
+    {
+      "code": 6028,
+      "name": "OracleDisabled",
+      "msg": "Oracle decisions are disabled for this pool"
+    },
+    {
+      "code": 6029,
+      "name": "OracleAlreadyDecided",
+      "msg": "Oracle has already decided this claim"
+    },
+    {
+      "code": 6030,
+      "name": "VotingDisabled",
+      "msg": "Member voting is disabled for this pool"
+    },
+    {
+      "code": 6031,
+      "name": "TooManyAssessors",
+      "msg": "Too many assessors (max 5)"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidAssessmentSignature",
+      "msg": "Missing or malformed Ed25519 assessment signature"
+    },
+    {
+      "code": 6033,
+      "name": "UnknownAssessor",
+      "msg": "Assessment was not signed by a registered assessor"
+    },
+    {
+      "code": 6034,
+      "name": "AssessmentExpired",
+      "msg": "Assessment has expired"
+    },
+    {
+      "code": 6035,
+      "name": "AssessmentMismatch",
+      "msg": "Assessment does not match claim"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidApprovedAmount",
+      "msg": "Approved amount exceeds requested amount"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidCommitteeConfig",
+      "msg": "Invalid committee: 1 <= threshold <= members (max 10), no duplicates"
+    },
+    {
+      "code": 6038,
+      "name": "NotCommitteeMember",
+      "msg": "Signer is not a committee member"
+    },
+    {
+      "code": 6039,
+      "name": "AlreadyAttested",
+      "msg": "Committee member has already attested this claim"
+    },
+    {
+      "code": 6040,
+      "name": "CommitteeThresholdNotMet",
+      "msg": "Not enough committee members signed"
+    },
+    {
+      "code": 6041,
+      "name": "MaxEvidenceReached",
+      "msg": "Maximum evidence items reached for this claim"
+    },
+    {
+      "code": 6042,
+      "name": "NotClaimant",
+      "msg": "Only the claimant can add evidence"
+    },
+    {
+      "code": 6043,
+      "name": "PolicyNotActive",
+      "msg": "Device policy is not active"
+    },
+    {
+      "code": 6044,
+      "name": "PolicyCapExceeded",
+      "msg": "Payout exceeds the device policy's remaining coverage"
+    },
+    {
+      "code": 6045,
+      "name": "InvalidPolicy",
+      "msg": "Policy account does not match claim"
+    },
+    {
+      "code": 6046,
+      "name": "PremiumsDisabled",
+      "msg": "Premium payments are not enabled for this pool"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidPremiumMonths",
+      "msg": "Premium must cover at least one month"
+    },
+    {
+      "code": 6048,
+      "name": "PremiumCoverageLapsed",
+      "msg": "Premium coverage has lapsed"
+    },
+    {
+      "code": 6049,
+      "name": "InsufficientPremiumStreak",
+      "msg": "Not enough consecutive premium months paid to claim"
+    },
+    {
+      "code": 6050,
+      "name": "ZeroShares",
+      "msg": "Amount is too small to mint or burn any shares"
+    },
+    {
+      "code": 6051,
+      "name": "SharesWorthless",
+      "msg": "Existing shares have no underlying value"
+    },
+    {
+      "code": 6052,
+      "name": "InsufficientShares",
+      "msg": "Insufficient underwriter shares"
+    },
+    {
+      "code": 6053,
+      "name": "UnderwriterCapitalLocked",
+      "msg": "Withdrawal would leave outstanding claim exposure uncovered"
+    },
+    {
+      "code": 6054,
+      "name": "CoverageAccountsRequired",
+      "msg": "Coverage mint and token account are required"
+    },
+    {
+      "code": 6055,
+      "name": "CoverageMintExists",
+      "msg": "Coverage mint already exists"
+    },
+    {
+      "code": 6056,
+      "name": "UnsupportedMintExtension",
+      "msg": "Accepted mint uses an unsupported Token-2022 extension"
+    },
+    {
+      "code": 6057,
+      "name": "UnsupportedAsset",
+      "msg": "Mint is not accepted by this pool"
+    },
+    {
+      "code": 6058,
+      "name": "TooManyAssets",
+      "msg": "Too many accepted assets"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidAssetRate",
+      "msg": "Asset rate must be positive"
+    },
+    {
+      "code": 6060,
+      "name": "AssetAlreadyAccepted",
+      "msg": "Asset is already accepted"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidFeeConfig",
+      "msg": "Fee exceeds the maximum"
+    },
+    {
+      "code": 6062,
+      "name": "TreasuryRequired",
+      "msg": "Treasury account required while a fee is set"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidStrategyConfig",
+      "msg": "Invalid strategy configuration"
+    },
+    {
+      "code": 6064,
+      "name": "StrategyNotConfigured",
+      "msg": "No strategy program is configured"
+    },
+    {
+      "code": 6065,
+      "name": "StrategyFundsDeployed",
+      "msg": "Funds are still deployed to the current strategy"
+    },
+    {
+      "code": 6066,
+      "name": "LiquidReserveBreached",
+      "msg": "Deployment would breach the minimum liquid reserve"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidBackstopConfig",
+      "msg": "Backstop share exceeds the maximum"
+    },
+    {
+      "code": 6068,
+      "name": "BackstopRequired",
+      "msg": "Backstop vault required while the backstop premium share is set"
+    },
+    {
+      "code": 6069,
+      "name": "BackstopEmpty",
+      "msg": "Backstop vault is empty"
+    },
+    {
+      "code": 6070,
+      "name": "ReserveRatioBreached",
+      "msg": "Withdrawal would breach the pool's minimum reserve ratio"
+    },
+    {
+      "code": 6071,
+      "name": "DepositBelowMinimum",
+      "msg": "Deposit is below the pool minimum"
+    },
+    {
+      "code": 6072,
+      "name": "MemberDepositCapExceeded",
+      "msg": "Deposit exceeds the per-member maximum"
+    },
+    {
+      "code": 6073,
+      "name": "PoolCapacityExceeded",
+      "msg": "Deposit exceeds the pool capacity"
+    },
+    {
+      "code": 6074,
+      "name": "LifetimeCapExceeded",
+      "msg": "Claim exceeds the member's lifetime payout cap"
+    },
+    {
+      "code": 6075,
+      "name": "NotAllowlisted",
+      "msg": "Wallet is not on the pool allowlist"
+    },
+    {
+      "code": 6076,
+      "name": "InstallmentsNotRequired",
+      "msg": "Claim is below the installment threshold"
+    },
+    {
+      "code": 6077,
+      "name": "PayoutVaultMismatch",
+      "msg": "Pool vault does not hold the claim's payout mint"
+    },
+    {
+      "code": 6078,
+      "name": "StrategyLossExceedsDeployed",
+      "msg": "Loss exceeds the funds deployed to the strategy"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidAssetVault",
+      "msg": "Asset vaults must be the pool authority's, in the pool's asset order"
+    },
+    {
+      "code": 6080,
+      "name": "InvalidReserveConfig",
+      "msg": "Reserve ratio exceeds 100%"
+    },
+    {
+      "code": 6081,
+      "name": "NoVotingWeight",
+      "msg": "Voter held no shares before the claim was submitted"
+    },
+    {
+      "code": 6082,
+      "name": "PolicyStillActive",
+      "msg": "Renewal would not extend the policy's coverage"
+    },
+    {
+      "code": 6083,
+      "name": "CommitteeDisabled",
+      "msg": "Assessor committee is disabled"
+    },
+    {
+      "code": 6084,
+      "name": "MemberDepositsDisabled",
+      "msg": "Members cannot deposit while premiums are charged, capital comes from underwriters"
+    },
+    {
+      "code": 6085,
+      "name": "NoCoverageCredit",
+      "msg": "Claimant holds no coverage credit"
+    },
+    {
+      "code": 6086,
+      "name": "CoverageStillActive",
+      "msg": "Premium coverage is still active"
+    },
+    {
+      "code": 6087,
+      "name": "InvalidDeclaredValue",
+      "msg": "Declared value must be positive and within the pool's cap"
+    },
+    {
+      "code": 6088,
+      "name": "PremiumsRequireDecider",
+      "msg": "Premium pools need an oracle, assessor or committee to decide claims"
+    },
+    {
+      "code": 6089,
+      "name": "StrategyDepositMismatch",
+      "msg": "Strategy did not take exactly the deployed amount"
+    }
+  ]
 };
@@ -1,6 +1,7 @@
 import { AnchorProvider, BN, Program, type Idl } from "@coral-xyz/anchor";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
   createAssociatedTokenAccountInstruction,
   getAssociatedTokenAddressSync,
@@ -32,7 +33,7 @@ export function getProgram(wallet: WalletContextState["wallet"] | null, publicKe
     { commitment: "confirmed" }
   );
 
-  return new Program(GADGETGUARD_IDL as Idl, PROGRAM_ID, provider);
+  return new Program({ ...GADGETGUARD_IDL, address: PROGRAM_ID.toBase58() } as Idl, provider);
 }
 
 export function derivePoolPda(): [PublicKey, number] {
@@ -53,17 +54,44 @@ export function deriveClaimPda(pool: PublicKey, claimId: bigint): [PublicKey, nu
   return PublicKey.findProgramAddressSync([Buffer.from("claim"), pool.toBuffer(), seed], PROGRAM_ID);
 }
 
+export function derivePayoutSchedulePda(claim: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("payout_schedule"), claim.toBuffer()], PROGRAM_ID);
+}
+
+export function deriveShareMintPda(pool: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("share_mint"), pool.toBuffer()], PROGRAM_ID);
+}
+
+export function deriveTreasuryPda(pool: PublicKey, mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("treasury"), pool.toBuffer(), mint.toBuffer()], PROGRAM_ID);
+}
+
+export function deriveVendorPda(pool: PublicKey, wallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("vendor"), pool.toBuffer(), wallet.toBuffer()], PROGRAM_ID);
+}
+
+export async function sha256(data: string | Uint8Array): Promise<Uint8Array> {
+  const bytes = typeof data === "string" ? new TextEncoder().encode(data) : data;
+  return new Uint8Array(await crypto.subtle.digest("SHA-256", bytes));
+}
+
+export async function derivePolicyPda(pool: PublicKey, owner: PublicKey, serial: string): Promise<[PublicKey, number]> {
+  const serialHash = await sha256(serial);
+  return PublicKey.findProgramAddressSync([Buffer.from("policy"), pool.toBuffer(), owner.toBuffer(), serialHash], PROGRAM_ID);
+}
+
 export async function ensureAta(
   wallet: WalletContextState,
   mint: PublicKey,
   owner: PublicKey,
-  allowOwnerOffCurve = false
+  allowOwnerOffCurve = false,
+  tokenProgram = TOKEN_PROGRAM_ID
 ): Promise<{ ata: PublicKey; createdTx?: string }> {
   if (!wallet.publicKey) {
     throw new Error("Wallet not connected");
   }
 
-  const ata = getAssociatedTokenAddressSync(mint, owner, allowOwnerOffCurve, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
+  const ata = getAssociatedTokenAddressSync(mint, owner, allowOwnerOffCurve, tokenProgram, ASSOCIATED_TOKEN_PROGRAM_ID);
   const info = await connection.getAccountInfo(ata);
 
   if (info) {
@@ -75,7 +103,7 @@ export async function ensureAta(
     ata,
     owner,
     mint,
-    TOKEN_PROGRAM_ID,
+    tokenProgram,
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
@@ -95,12 +123,13 @@ export function toClaimVariant(claimType: "damage" | "theft" | "loss") {
   return { loss: {} };
 }
 
-export function claimStatusLabel(status: unknown): "PENDING" | "APPROVED" | "REJECTED" | "PAID" {
+export function claimStatusLabel(status: unknown): "PENDING" | "APPROVED" | "REJECTED" | "PAID" | "PAYING" {
   const value = status as Record<string, unknown>;
   if (value.paid !== undefined) return "PAID";
+  if (value.paying !== undefined) return "PAYING";
   if (value.approved !== undefined) return "APPROVED";
   if (value.rejected !== undefined) return "REJECTED";
   return "PENDING";
 }
 
-export { BN, TOKEN_PROGRAM_ID, SystemProgram };
+export { BN, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, SystemProgram };
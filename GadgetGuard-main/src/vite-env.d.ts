@@ -6,7 +6,6 @@ interface ImportMetaEnv {
   readonly VITE_POOL_ADDRESS?: string
   readonly VITE_TEST_MINT_ADDRESS?: string
   readonly VITE_TEST_MINT_DECIMALS?: string
-  readonly VITE_VOTE_WINDOW_SECS?: string
 }
 
 interface ImportMeta {
@@ -50,4 +50,226 @@ pub enum ErrorCode {
 
     #[msg("Claimant account does not match claim")]
     InvalidClaimant,
+
+    #[msg("Signer is not the pool admin")]
+    Unauthorized,
+
+    #[msg("Claim is not in approved status")]
+    ClaimNotApproved,
+
+    #[msg("Claim must be paid in installments, payout schedule account required")]
+    PayoutScheduleRequired,
+
+    #[msg("No installment tranche is due yet")]
+    NoTrancheDue,
+
+    #[msg("Vendor account does not match claim")]
+    InvalidVendor,
+
+    #[msg("Vendor is not active")]
+    VendorNotActive,
+
+    #[msg("Payee token account does not match claim")]
+    InvalidPayee,
+
+    #[msg("Claim has no outstanding shortfall")]
+    NoShortfall,
+
+    #[msg("Pool owes unpaid claims, withdrawals are paused")]
+    PoolInShortfall,
+
+    #[msg("Claim is not being paid out")]
+    ClaimNotPaying,
+
+    #[msg("Payout timelock has not expired yet")]
+    PayoutTimelockActive,
+
+    #[msg("Signer is not the pool oracle")]
+    InvalidOracle,
+
+    #[msg("Oracle decisions are disabled for this pool")]
+    OracleDisabled,
+
+    #[msg("Oracle has already decided this claim")]
+    OracleAlreadyDecided,
+
+    #[msg("Member voting is disabled for this pool")]
+    VotingDisabled,
+
+    #[msg("Too many assessors (max 5)")]
+    TooManyAssessors,
+
+    #[msg("Missing or malformed Ed25519 assessment signature")]
+    InvalidAssessmentSignature,
+
+    #[msg("Assessment was not signed by a registered assessor")]
+    UnknownAssessor,
+
+    #[msg("Assessment has expired")]
+    AssessmentExpired,
+
+    #[msg("Assessment does not match claim")]
+    AssessmentMismatch,
+
+    #[msg("Approved amount exceeds requested amount")]
+    InvalidApprovedAmount,
+
+    #[msg("Invalid committee: 1 <= threshold <= members (max 10), no duplicates")]
+    InvalidCommitteeConfig,
+
+    #[msg("Signer is not a committee member")]
+    NotCommitteeMember,
+
+    #[msg("Committee member has already attested this claim")]
+    AlreadyAttested,
+
+    #[msg("Not enough committee members signed")]
+    CommitteeThresholdNotMet,
+
+    #[msg("Maximum evidence items reached for this claim")]
+    MaxEvidenceReached,
+
+    #[msg("Only the claimant can add evidence")]
+    NotClaimant,
+
+    #[msg("Device policy is not active")]
+    PolicyNotActive,
+
+    #[msg("Payout exceeds the device policy's remaining coverage")]
+    PolicyCapExceeded,
+
+    #[msg("Policy account does not match claim")]
+    InvalidPolicy,
+
+    #[msg("Premium payments are not enabled for this pool")]
+    PremiumsDisabled,
+
+    #[msg("Premium must cover at least one month")]
+    InvalidPremiumMonths,
+
+    #[msg("Premium coverage has lapsed")]
+    PremiumCoverageLapsed,
+
+    #[msg("Not enough consecutive premium months paid to claim")]
+    InsufficientPremiumStreak,
+
+    #[msg("Amount is too small to mint or burn any shares")]
+    ZeroShares,
+
+    #[msg("Existing shares have no underlying value")]
+    SharesWorthless,
+
+    #[msg("Insufficient underwriter shares")]
+    InsufficientShares,
+
+    #[msg("Withdrawal would leave outstanding claim exposure uncovered")]
+    UnderwriterCapitalLocked,
+
+    #[msg("Coverage mint and token account are required")]
+    CoverageAccountsRequired,
+
+    #[msg("Coverage mint already exists")]
+    CoverageMintExists,
+
+    #[msg("Accepted mint uses an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+
+    #[msg("Mint is not accepted by this pool")]
+    UnsupportedAsset,
+
+    #[msg("Too many accepted assets")]
+    TooManyAssets,
+
+    #[msg("Asset rate must be positive")]
+    InvalidAssetRate,
+
+    #[msg("Asset is already accepted")]
+    AssetAlreadyAccepted,
+
+    #[msg("Fee exceeds the maximum")]
+    InvalidFeeConfig,
+
+    #[msg("Treasury account required while a fee is set")]
+    TreasuryRequired,
+
+    #[msg("Invalid strategy configuration")]
+    InvalidStrategyConfig,
+
+    #[msg("No strategy program is configured")]
+    StrategyNotConfigured,
+
+    #[msg("Funds are still deployed to the current strategy")]
+    StrategyFundsDeployed,
+
+    #[msg("Deployment would breach the minimum liquid reserve")]
+    LiquidReserveBreached,
+
+    #[msg("Backstop share exceeds the maximum")]
+    InvalidBackstopConfig,
+
+    #[msg("Backstop vault required while the backstop premium share is set")]
+    BackstopRequired,
+
+    #[msg("Backstop vault is empty")]
+    BackstopEmpty,
+
+    #[msg("Withdrawal would breach the pool's minimum reserve ratio")]
+    ReserveRatioBreached,
+
+    #[msg("Deposit is below the pool minimum")]
+    DepositBelowMinimum,
+
+    #[msg("Deposit exceeds the per-member maximum")]
+    MemberDepositCapExceeded,
+
+    #[msg("Deposit exceeds the pool capacity")]
+    PoolCapacityExceeded,
+
+    #[msg("Claim exceeds the member's lifetime payout cap")]
+    LifetimeCapExceeded,
+
+    #[msg("Wallet is not on the pool allowlist")]
+    NotAllowlisted,
+
+    #[msg("Claim is below the installment threshold")]
+    InstallmentsNotRequired,
+
+    #[msg("Pool vault does not hold the claim's payout mint")]
+    PayoutVaultMismatch,
+
+    #[msg("Loss exceeds the funds deployed to the strategy")]
+    StrategyLossExceedsDeployed,
+
+    #[msg("Asset vaults must be the pool authority's, in the pool's asset order")]
+    InvalidAssetVault,
+
+    #[msg("Reserve ratio exceeds 100%")]
+    InvalidReserveConfig,
+
+    #[msg("Voter held no shares before the claim was submitted")]
+    NoVotingWeight,
+
+    #[msg("Renewal would not extend the policy's coverage")]
+    PolicyStillActive,
+
+    #[msg("Assessor committee is disabled")]
+    CommitteeDisabled,
+
+    #[msg("Members cannot deposit while premiums are charged, capital comes from underwriters")]
+    MemberDepositsDisabled,
+
+    #[msg("Claimant holds no coverage credit")]
+    NoCoverageCredit,
+
+    #[msg("Premium coverage is still active")]
+    CoverageStillActive,
+
+    #[msg("Declared value must be positive and within the pool's cap")]
+    InvalidDeclaredValue,
+
+    #[msg("Premium pools need an oracle, assessor or committee to decide claims")]
+    PremiumsRequireDecider,
+
+    #[msg("Strategy did not take exactly the deployed amount")]
+    StrategyDepositMismatch,
 }
@@ -1,5 +1,21 @@
+// Anchor 0.30 macros check cfgs for features this crate does not declare
+#![allow(unexpected_cfgs)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_2022::{self, spl_token_2022, Token2022};
+use anchor_spl::token_interface::{
+    self, Burn, Mint, MintTo, NonTransferableMintInitialize, PermanentDelegateInitialize, TokenAccount,
+    TokenInterface, TransferChecked,
+};
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
 
 pub mod errors;
 pub mod state;
@@ -9,6 +25,15 @@ use state::*;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
+/// Default coverage term for device policies (365 days)
+pub const DEFAULT_POLICY_TERM_SECS: i64 = 365 * 24 * 60 * 60;
+
+/// Length of one premium month (30 days)
+pub const PREMIUM_MONTH_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Default period over which payouts count against a member's claim limit (365 days)
+pub const DEFAULT_CLAIM_PERIOD_SECS: i64 = 365 * 24 * 60 * 60;
+
 #[program]
 pub mod gadgetguard {
     use super::*;
@@ -26,18 +51,62 @@ pub mod gadgetguard {
             ErrorCode::InvalidGovernanceConfig
         );
 
+        validate_accepted_mint(&ctx.accounts.accepted_mint)?;
+
         let pool = &mut ctx.accounts.pool;
         
         pool.admin = ctx.accounts.admin.key();
         pool.accepted_mint = ctx.accounts.accepted_mint.key();
+        pool.base_decimals = ctx.accounts.accepted_mint.decimals;
+        pool.assets = Vec::new();
         pool.total_deposits = 0;
         pool.total_paid_out = 0;
+        pool.total_shortfall = 0;
+        pool.payout_delay_secs = 0;
+        pool.policy_term_secs = DEFAULT_POLICY_TERM_SECS;
+        pool.max_declared_value = 0;
+        pool.premium_per_month = 0;
+        pool.min_months_for_claim = 0;
+        pool.total_premiums = 0;
+        pool.coverage_mint = Pubkey::default();
+        pool.underwriter_capital = 0;
+        pool.premium_income = 0;
+        pool.underwriter_shares = 0;
+        pool.outstanding_exposure = 0;
+        pool.deposit_fee_bps = 0;
+        pool.premium_fee_bps = 0;
+        pool.payout_fee_bps = 0;
+        pool.fees_collected = 0;
+        pool.strategy_program = Pubkey::default();
+        pool.min_liquid_reserve_bps = 10000;
+        pool.strategy_deployed = 0;
+        pool.backstop_premium_bps = 0;
+        pool.total_backstop_draws = 0;
+        pool.min_reserve_bps = 0;
+        pool.min_deposit = 0;
+        pool.max_deposit_per_member = 0;
+        pool.pool_capacity = 0;
+        pool.claim_vesting_secs = 0;
+        pool.claim_period_secs = DEFAULT_CLAIM_PERIOD_SECS;
+        pool.lifetime_payout_cap = 0;
+        pool.allowlist_root = [0; 32];
         pool.claim_count = 0;
         pool.member_count = 0;
         pool.max_claim_pct = max_claim_pct;
         pool.vote_window_secs = vote_window_secs;
         pool.quorum = quorum;
         pool.approval_ratio = approval_ratio;
+        pool.oracle = Pubkey::default();
+        pool.decision_mode = DecisionMode::VotesOnly;
+        pool.assessors = Vec::new();
+        pool.committee_active = false;
+        pool.claim_type_rules = [ClaimTypeRules {
+            enabled: true,
+            max_payout_bps: 10000,
+            vote_window_secs,
+            quorum,
+            approval_ratio,
+        }; ClaimType::COUNT];
         pool.bump = ctx.bumps.pool;
         
         msg!("Pool initialized by admin: {}", ctx.accounts.admin.key());
@@ -46,17 +115,169 @@ pub mod gadgetguard {
         Ok(())
     }
 
+    /// Configure the rules for one claim type (admin only)
+    pub fn configure_claim_type(
+        ctx: Context<ConfigureClaimType>,
+        claim_type: ClaimType,
+        rules: ClaimTypeRules,
+    ) -> Result<()> {
+        require!(rules.is_valid(), ErrorCode::InvalidGovernanceConfig);
+
+        let pool = &mut ctx.accounts.pool;
+        pool.claim_type_rules[claim_type.index()] = rules;
+
+        msg!("Claim type {} configured: enabled: {}, max payout bps: {}, quorum: {}, approval ratio: {}",
+            claim_type.index(), rules.enabled, rules.max_payout_bps, rules.quorum, rules.approval_ratio);
+
+        Ok(())
+    }
+
+    /// Create the non-transferable Token-2022 mint representing shares of the pool (admin only)
+    pub fn create_share_mint(ctx: Context<CreateShareMint>) -> Result<()> {
+        let pool_key = ctx.accounts.pool.key();
+        let mint_seeds = &[
+            b"share_mint",
+            pool_key.as_ref(),
+            &[ctx.bumps.share_mint],
+        ];
+        
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::NonTransferable,
+        ])?;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: ctx.accounts.share_mint.to_account_info(),
+                },
+                &[&mint_seeds[..]],
+            ),
+            lamports,
+            space as u64,
+            &ctx.accounts.token_2022_program.key(),
+        )?;
+        
+        // Shares cannot be moved between wallets, so votes and claim limits cannot borrow them
+        let token_program = ctx.accounts.token_2022_program.to_account_info();
+        let mint = ctx.accounts.share_mint.to_account_info();
+        token_interface::non_transferable_mint_initialize(CpiContext::new(
+            token_program.clone(),
+            NonTransferableMintInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ))?;
+        token_2022::initialize_mint2(
+            CpiContext::new(token_program, token_2022::InitializeMint2 { mint }),
+            ctx.accounts.accepted_mint.decimals,
+            &ctx.accounts.pool_authority.key(),
+            None,
+        )?;
+        
+        msg!("Pool share mint created: {}", ctx.accounts.share_mint.key());
+        
+        Ok(())
+    }
+
+    /// Create the non-transferable Token-2022 mint of coverage credits (admin only)
+    pub fn create_coverage_mint(ctx: Context<CreateCoverageMint>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.coverage_mint == Pubkey::default(), ErrorCode::CoverageMintExists);
+        
+        let pool_key = pool.key();
+        let mint_seeds = &[
+            b"coverage_mint",
+            pool_key.as_ref(),
+            &[ctx.bumps.coverage_mint],
+        ];
+        
+        // Allocate the mint with room for its extensions
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::PermanentDelegate,
+        ])?;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: ctx.accounts.coverage_mint.to_account_info(),
+                },
+                &[&mint_seeds[..]],
+            ),
+            lamports,
+            space as u64,
+            &ctx.accounts.token_2022_program.key(),
+        )?;
+        
+        // Credits cannot be transferred, and the pool authority can burn them on payout
+        let token_program = ctx.accounts.token_2022_program.to_account_info();
+        let mint = ctx.accounts.coverage_mint.to_account_info();
+        token_interface::non_transferable_mint_initialize(CpiContext::new(
+            token_program.clone(),
+            NonTransferableMintInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ))?;
+        token_interface::permanent_delegate_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                PermanentDelegateInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            &ctx.accounts.pool_authority.key(),
+        )?;
+        token_2022::initialize_mint2(
+            CpiContext::new(token_program, token_2022::InitializeMint2 { mint }),
+            0,
+            &ctx.accounts.pool_authority.key(),
+            None,
+        )?;
+        
+        pool.coverage_mint = ctx.accounts.coverage_mint.key();
+        
+        msg!("Coverage mint created: {}", pool.coverage_mint);
+        
+        Ok(())
+    }
+
     /// Join the pool as a new member
-    pub fn join_pool(ctx: Context<JoinPool>) -> Result<()> {
+    pub fn join_pool(ctx: Context<JoinPool>, proof: Vec<[u8; 32]>) -> Result<()> {
         let member = &mut ctx.accounts.member;
         let pool = &mut ctx.accounts.pool;
         
-        member.pool = ctx.accounts.pool.key();
+        // Gated pools only admit wallets on the allowlist
+        if pool.allowlist_root != [0; 32] {
+            let leaf = hash(ctx.accounts.user.key().as_ref()).to_bytes();
+            require!(
+                verify_merkle_proof(&proof, pool.allowlist_root, leaf),
+                ErrorCode::NotAllowlisted
+            );
+        }
+        
+        member.pool = pool.key();
         member.member = ctx.accounts.user.key();
         member.deposited_amount = 0;
         member.claim_limit = 0;
         member.last_claim_ts = 0;
         member.active = true;
+        member.coverage_until = 0;
+        member.months_paid_streak = 0;
+        member.premiums_paid = 0;
+        member.vesting_amount = 0;
+        member.vesting_start_ts = 0;
+        member.vesting_end_ts = 0;
+        member.total_claimed = 0;
+        member.claims_paid = 0;
+        member.recent_payouts = [PayoutRecord::default(); Member::MAX_RECENT_PAYOUTS];
+        member.pending_claims = 0;
+        member.last_deposit_slot = 0;
         member.bump = ctx.bumps.member;
         
         pool.member_count = pool.member_count.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
@@ -72,33 +293,272 @@ pub mod gadgetguard {
         let pool = &mut ctx.accounts.pool;
         
         require!(member.active, ErrorCode::MemberNotActive);
+        require!(pool.premium_per_month == 0, ErrorCode::MemberDepositsDisabled);
+        require!(amount > 0, ErrorCode::DepositBelowMinimum);
         
-        // Transfer tokens from member to pool vault
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.member_token_account.to_account_info(),
-            to: ctx.accounts.pool_vault.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, amount)?;
+        // Send the deposit fee straight to the treasury
+        let fee = collect_fee_from(
+            &ctx.accounts.token_program,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.user,
+            amount,
+            pool.deposit_fee_bps,
+        )?;
+        pool.fees_collected = pool.fees_collected.checked_add(fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Transfer the rest from member to pool vault, crediting what the vault received
+        let received = transfer_to_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.member_token_account,
+            &mut ctx.accounts.pool_vault,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.user,
+            amount.checked_sub(fee).ok_or(ErrorCode::ArithmeticOverflow)?,
+        )?;
+        require!(received > 0 && received >= pool.min_deposit, ErrorCode::DepositBelowMinimum);
+        
+        // Mint shares at the current share price
+        let supply = ctx.accounts.share_mint.supply;
+        let held = value_of_shares(ctx.accounts.member_share_account.amount, supply, pool.total_deposits)?;
+        check_deposit_limits(pool, held, received)?;
+        let shares = shares_for_amount(received, supply, pool.total_deposits)?;
+        require!(shares > 0, ErrorCode::ZeroShares);
+        
+        share_mint_cpi(
+            &ctx.accounts.share_token_program,
+            &ctx.accounts.share_mint,
+            &ctx.accounts.member_share_account,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            shares,
+        )?;
         
         // Update member and pool state
-        member.deposited_amount = member.deposited_amount.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let clock = Clock::get()?;
+        member.deposited_amount = member.deposited_amount.checked_add(received).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_deposits = pool.total_deposits.checked_add(received).ok_or(ErrorCode::ArithmeticOverflow)?;
+        member.add_vesting(received, clock.unix_timestamp, pool.claim_vesting_secs);
+        member.last_deposit_slot = clock.slot;
         
-        // Calculate claim limit: deposit * max_claim_pct / 10000
-        let claim_limit_increase = (amount as u128)
-            .checked_mul(pool.max_claim_pct as u128)
-            .ok_or(ErrorCode::ArithmeticOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::ArithmeticOverflow)? as u64;
+        // Claim limit: share value * max_claim_pct / 10000
+        let balance = ctx.accounts.member_share_account.amount.checked_add(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let supply = supply.checked_add(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let claim_limit = pool.claim_limit_for(value_of_shares(balance, supply, pool.total_deposits)?);
+        member.claim_limit = claim_limit;
+        
+        msg!("Member {} deposited {} tokens for {} shares, new claim limit: {}", 
+            ctx.accounts.user.key(), received, shares, member.claim_limit);
+        
+        Ok(())
+    }
+
+    /// Deposit an additional accepted asset, valued in accepted mint units at the asset's rate
+    pub fn deposit_asset(ctx: Context<DepositAsset>, amount: u64) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(member.active, ErrorCode::MemberNotActive);
+        require!(pool.premium_per_month == 0, ErrorCode::MemberDepositsDisabled);
+        
+        // Send the deposit fee straight to the asset's treasury
+        let fee = collect_fee_from(
+            &ctx.accounts.token_program,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.asset_mint,
+            &ctx.accounts.user,
+            amount,
+            pool.deposit_fee_bps,
+        )?;
+        let fee_value = pool.to_base_value(&ctx.accounts.asset_mint.key(), fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.fees_collected = pool.fees_collected.checked_add(fee_value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Transfer the rest from member to the asset's vault
+        let received = transfer_to_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.member_token_account,
+            &mut ctx.accounts.asset_vault,
+            &ctx.accounts.asset_mint,
+            &ctx.accounts.user,
+            amount.checked_sub(fee).ok_or(ErrorCode::ArithmeticOverflow)?,
+        )?;
+        
+        let value = pool
+            .to_base_value(&ctx.accounts.asset_mint.key(), received)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(value > 0 && value >= pool.min_deposit, ErrorCode::DepositBelowMinimum);
+        
+        // Mint shares at the current share price
+        let supply = ctx.accounts.share_mint.supply;
+        let held = value_of_shares(ctx.accounts.member_share_account.amount, supply, pool.total_deposits)?;
+        check_deposit_limits(pool, held, value)?;
+        let shares = shares_for_amount(value, supply, pool.total_deposits)?;
+        require!(shares > 0, ErrorCode::ZeroShares);
+        
+        share_mint_cpi(
+            &ctx.accounts.share_token_program,
+            &ctx.accounts.share_mint,
+            &ctx.accounts.member_share_account,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            shares,
+        )?;
+        
+        // Update member and pool state
+        member.deposited_amount = member.deposited_amount.checked_add(value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let clock = Clock::get()?;
+        pool.total_deposits = pool.total_deposits.checked_add(value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        member.add_vesting(value, clock.unix_timestamp, pool.claim_vesting_secs);
+        member.last_deposit_slot = clock.slot;
+        
+        let balance = ctx.accounts.member_share_account.amount.checked_add(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let supply = supply.checked_add(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let claim_limit = pool.claim_limit_for(value_of_shares(balance, supply, pool.total_deposits)?);
+        member.claim_limit = claim_limit;
         
-        member.claim_limit = member.claim_limit.checked_add(claim_limit_increase).ok_or(ErrorCode::ArithmeticOverflow)?;
+        msg!("Member {} deposited {} of {} worth {}, for {} shares", 
+            ctx.accounts.user.key(), received, ctx.accounts.asset_mint.key(), value, shares);
         
-        pool.total_deposits = pool.total_deposits.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Pay non-refundable premiums for a number of months
+    pub fn pay_premium(ctx: Context<PayPremium>, months: u32) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(member.active, ErrorCode::MemberNotActive);
+        require!(pool.premium_per_month > 0, ErrorCode::PremiumsDisabled);
+        require!(months > 0, ErrorCode::InvalidPremiumMonths);
+        
+        let amount = pool.premium_per_month.checked_mul(months as u64).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Send the premium fee straight to the treasury
+        let fee = collect_fee_from(
+            &ctx.accounts.token_program,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.user,
+            amount,
+            pool.premium_fee_bps,
+        )?;
+        pool.fees_collected = pool.fees_collected.checked_add(fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Send the backstop its share of the premium straight from the member
+        let net = amount.checked_sub(fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let backstop_share = bps_of(net, pool.backstop_premium_bps)?;
+        if backstop_share > 0 {
+            let backstop = ctx.accounts.backstop.as_mut().ok_or(ErrorCode::BackstopRequired)?;
+            transfer_to_vault(
+                &ctx.accounts.token_program,
+                &ctx.accounts.member_token_account,
+                backstop,
+                &ctx.accounts.accepted_mint,
+                &ctx.accounts.user,
+                backstop_share,
+            )?;
+        }
+        
+        // Transfer the rest of the premium from member to pool vault
+        let income = transfer_to_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.member_token_account,
+            &mut ctx.accounts.pool_vault,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.user,
+            net.checked_sub(backstop_share).ok_or(ErrorCode::ArithmeticOverflow)?,
+        )?;
+        
+        // Extend coverage, restarting the streak if it had lapsed
+        let clock = Clock::get()?;
+        let lapsed = !member.has_premium_coverage(clock.unix_timestamp);
+        let period = PREMIUM_MONTH_SECS.checked_mul(months as i64).ok_or(ErrorCode::ArithmeticOverflow)?;
+        if !lapsed {
+            member.months_paid_streak = member.months_paid_streak.checked_add(months).ok_or(ErrorCode::ArithmeticOverflow)?;
+            member.coverage_until = member.coverage_until.checked_add(period).ok_or(ErrorCode::ArithmeticOverflow)?;
+        } else {
+            member.months_paid_streak = months;
+            member.coverage_until = clock.unix_timestamp.checked_add(period).ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        member.premiums_paid = member.premiums_paid.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Mint one coverage credit per month paid
+        if pool.coverage_mint != Pubkey::default() {
+            let (Some(coverage_mint), Some(coverage_account), Some(token_2022_program)) = (
+                &ctx.accounts.coverage_mint,
+                &ctx.accounts.member_coverage_account,
+                &ctx.accounts.token_2022_program,
+            ) else {
+                return err!(ErrorCode::CoverageAccountsRequired);
+            };
+            // Credits left from a lapsed coverage period no longer count
+            if lapsed && coverage_account.amount > 0 {
+                burn_coverage_credits(
+                    token_2022_program,
+                    coverage_mint,
+                    coverage_account,
+                    &ctx.accounts.pool_authority,
+                    pool.key(),
+                    ctx.bumps.pool_authority,
+                    coverage_account.amount,
+                )?;
+            }
+            let pool_key = pool.key();
+            let seeds = &[
+                b"pool_authority",
+                pool_key.as_ref(),
+                &[ctx.bumps.pool_authority],
+            ];
+            let signer = &[&seeds[..]];
+            let cpi_accounts = token_2022::MintTo {
+                mint: coverage_mint.to_account_info(),
+                to: coverage_account.to_account_info(),
+                authority: ctx.accounts.pool_authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_2022_program.to_account_info(), cpi_accounts, signer);
+            token_2022::mint_to(cpi_ctx, months as u64)?;
+        }
+        
+        pool.total_premiums = pool.total_premiums.checked_add(income).ok_or(ErrorCode::ArithmeticOverflow)?;
+        // Premiums go to underwriters, or to member shares while the pool has none
+        if pool.underwriter_shares > 0 {
+            pool.premium_income = pool.premium_income.checked_add(income).ok_or(ErrorCode::ArithmeticOverflow)?;
+        } else {
+            pool.total_deposits = pool.total_deposits.checked_add(income).ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        msg!("Member {} paid {} tokens for {} months, covered until {} (streak {})", 
+            ctx.accounts.user.key(), amount, months, member.coverage_until, member.months_paid_streak);
+        
+        Ok(())
+    }
+
+    /// Burn a member's coverage credits once their premium coverage has lapsed (permissionless crank)
+    pub fn expire_coverage_credits(ctx: Context<ExpireCoverageCredits>) -> Result<()> {
+        let member = &ctx.accounts.member;
+        let coverage_account = &ctx.accounts.member_coverage_account;
+        
+        let clock = Clock::get()?;
+        require!(!member.has_premium_coverage(clock.unix_timestamp), ErrorCode::CoverageStillActive);
+        require!(coverage_account.amount > 0, ErrorCode::NoCoverageCredit);
+        
+        let credits = coverage_account.amount;
+        burn_coverage_credits(
+            &ctx.accounts.token_2022_program,
+            &ctx.accounts.coverage_mint,
+            coverage_account,
+            &ctx.accounts.pool_authority,
+            ctx.accounts.pool.key(),
+            ctx.bumps.pool_authority,
+            credits,
+        )?;
         
-        msg!("Member {} deposited {} tokens, new claim limit: {}", 
-            ctx.accounts.user.key(), amount, member.claim_limit);
+        msg!("Expired {} coverage credits of {}", credits, member.member);
         
         Ok(())
     }
@@ -109,44 +569,246 @@ pub mod gadgetguard {
         let pool = &mut ctx.accounts.pool;
         
         require!(member.active, ErrorCode::MemberNotActive);
-        require!(member.deposited_amount >= amount, ErrorCode::InsufficientMemberDeposit);
         require!(ctx.accounts.pool_vault.amount >= amount, ErrorCode::InsufficientPoolFunds);
+        require!(pool.total_shortfall == 0, ErrorCode::PoolInShortfall);
         
-        // For MVP: simple withdrawal, no cooldown check or pending claim check
-        // (In production, you'd check for pending claims)
+        // Redeem shares at the current share price, rounded against the member
+        let supply = ctx.accounts.share_mint.supply;
+        let shares = shares_to_burn(amount, supply, pool.total_deposits)?;
+        require!(shares > 0, ErrorCode::ZeroShares);
+        require!(ctx.accounts.member_share_account.amount >= shares, ErrorCode::InsufficientMemberDeposit);
         
         // Transfer tokens from pool vault to member
-        let seeds = &[
-            b"pool_authority",
-            pool.key().as_ref(),
-            &[ctx.bumps.pool_authority],
-        ];
-        let signer = &[&seeds[..]];
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_vault,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            amount,
+        )?;
         
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.pool_vault.to_account_info(),
-            to: ctx.accounts.member_token_account.to_account_info(),
-            authority: ctx.accounts.pool_authority.to_account_info(),
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            from: ctx.accounts.member_share_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
         };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, amount)?;
+        let cpi_ctx = CpiContext::new(ctx.accounts.share_token_program.to_account_info(), cpi_accounts);
+        token_interface::burn(cpi_ctx, shares)?;
         
-        // Update member and pool state
-        member.deposited_amount = member.deposited_amount.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        // Update member and pool state, recomputing the claim limit from the remaining shares
+        let balance = ctx.accounts.member_share_account.amount.checked_sub(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let supply = supply.checked_sub(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        record_withdrawal(pool, member, amount, balance, supply)?;
         
-        // Reduce claim limit proportionally
-        let claim_limit_decrease = (amount as u128)
-            .checked_mul(pool.max_claim_pct as u128)
-            .ok_or(ErrorCode::ArithmeticOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::ArithmeticOverflow)? as u64;
+        // Funds left in the pool's vaults or deployed to the strategy must still cover the reserve
+        let base_left = ctx.accounts.pool_vault.amount.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        check_reserve(pool, &ctx.accounts.pool_authority.key(), base_left, ctx.remaining_accounts)?;
+        
+        msg!("Member {} withdrew {} tokens, burning {} shares", ctx.accounts.user.key(), amount, shares);
+        
+        Ok(())
+    }
+
+    /// Withdraw `value` base units of share value in an additional accepted asset
+    pub fn withdraw_asset(ctx: Context<WithdrawAsset>, value: u64) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(member.active, ErrorCode::MemberNotActive);
+        require!(pool.total_shortfall == 0, ErrorCode::PoolInShortfall);
+        
+        // Pay out asset tokens worth `value`, rounded against the member
+        let asset_mint = ctx.accounts.asset_mint.key();
+        let amount = pool.from_base_value(&asset_mint, value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(amount > 0, ErrorCode::ZeroShares);
+        require!(ctx.accounts.asset_vault.amount >= amount, ErrorCode::InsufficientPoolFunds);
+        
+        // Redeem shares at the current share price, rounded against the member
+        let supply = ctx.accounts.share_mint.supply;
+        let shares = shares_to_burn(value, supply, pool.total_deposits)?;
+        require!(shares > 0, ErrorCode::ZeroShares);
+        require!(ctx.accounts.member_share_account.amount >= shares, ErrorCode::InsufficientMemberDeposit);
+        
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.asset_vault,
+            &ctx.accounts.member_token_account,
+            &ctx.accounts.asset_mint,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            amount,
+        )?;
+        
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            from: ctx.accounts.member_share_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.share_token_program.to_account_info(), cpi_accounts);
+        token_interface::burn(cpi_ctx, shares)?;
+        
+        // Update member and pool state, recomputing the claim limit from the remaining shares
+        let balance = ctx.accounts.member_share_account.amount.checked_sub(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let supply = supply.checked_sub(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        record_withdrawal(pool, member, value, balance, supply)?;
+        
+        // Funds left in the pool's vaults or deployed to the strategy must still cover the reserve
+        check_reserve(pool, &ctx.accounts.pool_authority.key(), ctx.accounts.pool_vault.amount, ctx.remaining_accounts)?;
+        
+        msg!("Member {} withdrew {} of {} worth {}, burning {} shares", 
+            ctx.accounts.user.key(), amount, asset_mint, value, shares);
+        
+        Ok(())
+    }
+
+    /// Register as an underwriter providing capital to the pool
+    pub fn register_underwriter(ctx: Context<RegisterUnderwriter>) -> Result<()> {
+        let underwriter = &mut ctx.accounts.underwriter;
+        
+        underwriter.pool = ctx.accounts.pool.key();
+        underwriter.owner = ctx.accounts.user.key();
+        underwriter.shares = 0;
+        underwriter.contributed_amount = 0;
+        underwriter.bump = ctx.bumps.underwriter;
+        
+        msg!("Underwriter {} registered", underwriter.owner);
+        
+        Ok(())
+    }
+
+    /// Deposit underwriting capital in exchange for shares at the current share price
+    pub fn underwrite(ctx: Context<Underwrite>, amount: u64) -> Result<()> {
+        let underwriter = &mut ctx.accounts.underwriter;
+        let pool = &mut ctx.accounts.pool;
+        
+        // Transfer capital from underwriter to pool vault
+        let received = transfer_to_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.user_token_account,
+            &mut ctx.accounts.pool_vault,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.user,
+            amount,
+        )?;
+        
+        let shares = shares_for_amount(received, pool.underwriter_shares, pool.underwriter_value())?;
+        require!(shares > 0, ErrorCode::ZeroShares);
+        
+        underwriter.shares = underwriter.shares.checked_add(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        underwriter.contributed_amount = underwriter.contributed_amount.checked_add(received).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        pool.underwriter_shares = pool.underwriter_shares.checked_add(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.underwriter_capital = pool.underwriter_capital.checked_add(received).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Underwriter {} deposited {} tokens for {} shares", 
+            ctx.accounts.user.key(), received, shares);
+        
+        Ok(())
+    }
+
+    /// Withdraw underwriting capital not needed to cover outstanding claim exposure
+    pub fn withdraw_capital(ctx: Context<WithdrawCapital>, amount: u64) -> Result<()> {
+        let underwriter = &mut ctx.accounts.underwriter;
+        let pool = &mut ctx.accounts.pool;
+        
+        let value = pool.underwriter_value();
+        let free_capital = value.saturating_sub(pool.outstanding_exposure);
+        require!(amount <= free_capital, ErrorCode::UnderwriterCapitalLocked);
+        require!(ctx.accounts.pool_vault.amount >= amount, ErrorCode::InsufficientPoolFunds);
+        
+        // Burn shares rounded up so remaining holders are never diluted
+        let shares = shares_to_burn(amount, pool.underwriter_shares, value)?;
+        require!(shares > 0, ErrorCode::ZeroShares);
+        require!(underwriter.shares >= shares, ErrorCode::InsufficientShares);
+        
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_vault,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            amount,
+        )?;
+        
+        underwriter.shares = underwriter.shares.checked_sub(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.underwriter_shares = pool.underwriter_shares.checked_sub(shares).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.draw_underwriter_funds(amount);
+        
+        // Funds left behind by the last underwriter go to member shares, not the next underwriter
+        if pool.underwriter_shares == 0 {
+            let leftover = pool.underwriter_value();
+            pool.underwriter_capital = 0;
+            pool.premium_income = 0;
+            pool.total_deposits = pool.total_deposits.checked_add(leftover).ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        msg!("Underwriter {} withdrew {} tokens, burning {} shares", 
+            ctx.accounts.user.key(), amount, shares);
+        
+        Ok(())
+    }
+
+    /// Register a device and insure it under a new policy
+    pub fn register_device(
+        ctx: Context<RegisterDevice>,
+        category: DeviceCategory,
+        serial_hash: [u8; 32],
+        declared_value: u64,
+    ) -> Result<()> {
+        let member = &ctx.accounts.member;
+        let pool = &ctx.accounts.pool;
+        let policy = &mut ctx.accounts.policy;
+        
+        require!(member.active, ErrorCode::MemberNotActive);
+        require!(declared_value > 0, ErrorCode::InvalidDeclaredValue);
+        require!(
+            pool.max_declared_value == 0 || declared_value <= pool.max_declared_value,
+            ErrorCode::InvalidDeclaredValue
+        );
+        
+        let clock = Clock::get()?;
+        
+        policy.pool = pool.key();
+        policy.owner = ctx.accounts.user.key();
+        policy.category = category;
+        policy.serial_hash = serial_hash;
+        policy.declared_value = declared_value;
+        policy.coverage_start = clock.unix_timestamp;
+        policy.coverage_end = clock.unix_timestamp.checked_add(pool.policy_term_secs).ok_or(ErrorCode::ArithmeticOverflow)?;
+        policy.total_paid = 0;
+        policy.pending_amount = 0;
+        policy.bump = ctx.bumps.policy;
+        
+        msg!("Device registered by {} with declared value {}, covered until {}", 
+            policy.owner, declared_value, policy.coverage_end);
+        
+        Ok(())
+    }
+
+    /// Renew a device policy for another term from now, keeping its payout history
+    pub fn renew_policy(ctx: Context<RenewPolicy>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let policy = &mut ctx.accounts.policy;
+        
+        require!(ctx.accounts.member.active, ErrorCode::MemberNotActive);
         
-        member.claim_limit = member.claim_limit.saturating_sub(claim_limit_decrease);
+        let clock = Clock::get()?;
+        let coverage_end = clock.unix_timestamp.checked_add(pool.policy_term_secs).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(coverage_end > policy.coverage_end, ErrorCode::PolicyStillActive);
         
-        pool.total_deposits = pool.total_deposits.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        // A lapsed policy covers again only from the renewal
+        if !policy.is_active(clock.unix_timestamp) {
+            policy.coverage_start = clock.unix_timestamp;
+        }
+        policy.coverage_end = coverage_end;
         
-        msg!("Member {} withdrew {} tokens", ctx.accounts.user.key(), amount);
+        msg!("Policy {} renewed, covered until {}", policy.key(), coverage_end);
         
         Ok(())
     }
@@ -157,42 +819,160 @@ pub mod gadgetguard {
         claim_type: ClaimType,
         requested_amount: u64,
         evidence_uri: String,
+        evidence_hash: [u8; 32],
     ) -> Result<()> {
-        let member = &ctx.accounts.member;
+        let member = &mut ctx.accounts.member;
         let pool = &mut ctx.accounts.pool;
         let claim = &mut ctx.accounts.claim;
         
         require!(member.active, ErrorCode::MemberNotActive);
-        require!(requested_amount <= member.claim_limit, ErrorCode::ClaimExceedsLimit);
+        
+        // In a premium pool policyholders are covered up to their device's declared value,
+        // within the pool's cap, which may have been lowered since registration;
+        // otherwise the claim limit follows the live value of the member's pool shares,
+        // less deposits still vesting
+        let clock = Clock::get()?;
+        let claim_limit = if pool.premium_per_month > 0 {
+            ctx.accounts.policy.declared_value.min(pool.max_declared_value)
+        } else {
+            let share_value = value_of_shares(
+                ctx.accounts.member_share_account.amount,
+                ctx.accounts.share_mint.supply,
+                pool.total_deposits,
+            )?;
+            let vested_value = share_value.saturating_sub(member.unvested_value(clock.unix_timestamp));
+            pool.claim_limit_for(vested_value)
+        };
+        
+        // Payouts in the last claim period and claims still pending use up the claim limit
+        let available_limit = claim_limit
+            .saturating_sub(member.claimed_in_period(clock.unix_timestamp, pool.claim_period_secs))
+            .saturating_sub(member.pending_claims);
+        
+        // Check claim type is enabled and within its payout cap
+        let rules = *pool.rules(claim_type);
+        require!(rules.enabled, ErrorCode::InvalidClaimType);
+        let max_payout = (available_limit as u128)
+            .checked_mul(rules.max_payout_bps as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::ArithmeticOverflow)? as u64;
+        require!(requested_amount <= max_payout, ErrorCode::ClaimExceedsLimit);
+        if pool.lifetime_payout_cap > 0 {
+            let lifetime_total = member
+                .total_claimed
+                .checked_add(member.pending_claims)
+                .and_then(|total| total.checked_add(requested_amount))
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            require!(lifetime_total <= pool.lifetime_payout_cap, ErrorCode::LifetimeCapExceeded);
+        }
         require!(evidence_uri.len() <= 200, ErrorCode::EvidenceUriTooLong);
         
-        // Check pool has enough funds
-        let available_funds = ctx.accounts.pool_vault.amount;
+        // Check premiums are paid up when the pool charges them
+        if pool.premium_per_month > 0 {
+            require!(member.has_premium_coverage(clock.unix_timestamp), ErrorCode::PremiumCoverageLapsed);
+            require!(
+                member.months_paid_streak >= pool.min_months_for_claim as u32,
+                ErrorCode::InsufficientPremiumStreak
+            );
+        }
+        
+        // Check the device policy is in force and has coverage left
+        let policy = &mut ctx.accounts.policy;
+        require!(policy.is_active(clock.unix_timestamp), ErrorCode::PolicyNotActive);
+        require!(requested_amount <= policy.remaining_coverage(), ErrorCode::PolicyCapExceeded);
+        
+        // Check the vault of the payout mint holds enough to pay the claim
+        let payout_mint = match &ctx.accounts.payout_mint {
+            Some(mint) => {
+                require!(pool.is_accepted(&mint.key()), ErrorCode::UnsupportedAsset);
+                mint.key()
+            }
+            None => pool.accepted_mint,
+        };
+        require_keys_eq!(ctx.accounts.pool_vault.mint, payout_mint, ErrorCode::PayoutVaultMismatch);
+        let available_funds = pool
+            .to_base_value(&payout_mint, ctx.accounts.pool_vault.amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
         require!(available_funds >= requested_amount, ErrorCode::InsufficientPoolFunds);
         
-        let clock = Clock::get()?;
         let claim_id = pool.claim_count;
         
-        claim.pool = ctx.accounts.pool.key();
+        claim.pool = pool.key();
         claim.claim_id = claim_id;
         claim.claimant = ctx.accounts.user.key();
+        claim.policy = policy.key();
         claim.claim_type = claim_type;
         claim.requested_amount = requested_amount;
+        claim.approved_amount = requested_amount;
+        claim.paid_amount = 0;
+        claim.shortfall_amount = 0;
+        claim.approved_ts = 0;
         claim.evidence_uri = evidence_uri;
+        claim.evidence_hash = evidence_hash;
+        claim.evidence_count = 0;
+        claim.vendor = match &ctx.accounts.vendor {
+            Some(vendor) => {
+                require!(vendor.active, ErrorCode::VendorNotActive);
+                Some(vendor.key())
+            }
+            None => None,
+        };
+        claim.payout_mint = payout_mint;
         claim.created_ts = clock.unix_timestamp;
+        claim.created_slot = clock.slot;
+        // Rule changes only apply to claims submitted afterwards
+        claim.vote_deadline = clock.unix_timestamp.checked_add(rules.vote_window_secs).ok_or(ErrorCode::ArithmeticOverflow)?;
+        claim.quorum = rules.quorum;
+        claim.approval_ratio = rules.approval_ratio;
         claim.status = ClaimStatus::Pending;
         claim.yes_votes = 0;
         claim.no_votes = 0;
+        claim.yes_weight = 0;
+        claim.no_weight = 0;
         claim.voters = Vec::new();
+        claim.oracle_decision = None;
+        claim.oracle_amount = 0;
+        claim.committee_epoch = 0;
+        claim.approve_attestations = 0;
+        claim.reject_attestations = 0;
         claim.bump = ctx.bumps.claim;
         
         pool.claim_count = pool.claim_count.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.outstanding_exposure = pool.outstanding_exposure.checked_add(requested_amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        member.pending_claims = member.pending_claims.checked_add(requested_amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        policy.pending_amount = policy.pending_amount.checked_add(requested_amount).ok_or(ErrorCode::ArithmeticOverflow)?;
         
         msg!("Claim {} submitted by {} for {} tokens", claim_id, ctx.accounts.user.key(), requested_amount);
         
         Ok(())
     }
 
+    /// Append evidence to a pending claim (claimant only)
+    pub fn add_evidence(ctx: Context<AddEvidence>, uri: String, hash: [u8; 32]) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let evidence = &mut ctx.accounts.evidence;
+        
+        require!(claim.status == ClaimStatus::Pending, ErrorCode::ClaimNotPending);
+        require!(claim.evidence_count < Claim::MAX_EVIDENCE, ErrorCode::MaxEvidenceReached);
+        require!(uri.len() <= 200, ErrorCode::EvidenceUriTooLong);
+        
+        let clock = Clock::get()?;
+        
+        evidence.claim = claim.key();
+        evidence.index = claim.evidence_count;
+        evidence.uri = uri;
+        evidence.hash = hash;
+        evidence.added_ts = clock.unix_timestamp;
+        evidence.bump = ctx.bumps.evidence;
+        
+        claim.evidence_count = claim.evidence_count.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Evidence {} added to claim {}", evidence.index, claim.claim_id);
+        
+        Ok(())
+    }
+
     /// Vote on a claim
     pub fn vote_claim(ctx: Context<VoteClaim>, vote_yes: bool) -> Result<()> {
         let claim = &mut ctx.accounts.claim;
@@ -201,11 +981,11 @@ pub mod gadgetguard {
         
         require!(member.active, ErrorCode::MemberNotActive);
         require!(claim.status == ClaimStatus::Pending, ErrorCode::ClaimNotPending);
+        require!(pool.decision_mode != DecisionMode::OracleOnly, ErrorCode::VotingDisabled);
         
         // Check vote window hasn't expired
         let clock = Clock::get()?;
-        let vote_deadline = claim.created_ts.checked_add(pool.vote_window_secs).ok_or(ErrorCode::ArithmeticOverflow)?;
-        require!(clock.unix_timestamp <= vote_deadline, ErrorCode::VoteWindowExpired);
+        require!(clock.unix_timestamp <= claim.vote_deadline, ErrorCode::VoteWindowExpired);
         
         // Check member hasn't already voted
         let voter_key = ctx.accounts.user.key();
@@ -215,12 +995,18 @@ pub mod gadgetguard {
         require!(claim.voters.len() < 32, ErrorCode::MaxVotersReached);
         claim.voters.push(voter_key);
         
+        // Votes are weighted by the voter's pool share balance, which counts only if it predates the claim
+        require!(member.last_deposit_slot < claim.created_slot, ErrorCode::NoVotingWeight);
+        let weight = ctx.accounts.member_share_account.amount;
+        require!(weight > 0, ErrorCode::NoVotingWeight);
         if vote_yes {
             claim.yes_votes = claim.yes_votes.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
-            msg!("Member {} voted YES on claim {}", voter_key, claim.claim_id);
+            claim.yes_weight = claim.yes_weight.checked_add(weight).ok_or(ErrorCode::ArithmeticOverflow)?;
+            msg!("Member {} voted YES on claim {} with weight {}", voter_key, claim.claim_id, weight);
         } else {
             claim.no_votes = claim.no_votes.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
-            msg!("Member {} voted NO on claim {}", voter_key, claim.claim_id);
+            claim.no_weight = claim.no_weight.checked_add(weight).ok_or(ErrorCode::ArithmeticOverflow)?;
+            msg!("Member {} voted NO on claim {} with weight {}", voter_key, claim.claim_id, weight);
         }
         
         Ok(())
@@ -232,182 +1018,3210 @@ pub mod gadgetguard {
         let pool = &mut ctx.accounts.pool;
         
         require!(claim.status == ClaimStatus::Pending, ErrorCode::ClaimNotPending);
-        require!(ctx.accounts.claimant.key() == claim.claimant, ErrorCode::InvalidClaimant);
+        require!(pool.decision_mode != DecisionMode::OracleOnly, ErrorCode::VotingDisabled);
         
         // Check vote window has expired
         let clock = Clock::get()?;
-        let vote_deadline = claim.created_ts.checked_add(pool.vote_window_secs).ok_or(ErrorCode::ArithmeticOverflow)?;
-        require!(clock.unix_timestamp > vote_deadline, ErrorCode::VoteWindowNotExpired);
+        require!(clock.unix_timestamp > claim.vote_deadline, ErrorCode::VoteWindowNotExpired);
         
-        // Check quorum
+        // Without quorum, a recorded oracle decision stands in OracleWithOverride mode
         let total_votes = claim.yes_votes.checked_add(claim.no_votes).ok_or(ErrorCode::ArithmeticOverflow)?;
-        require!(total_votes >= pool.quorum, ErrorCode::QuorumNotReached);
-        
-        // Calculate approval ratio: yes_votes / total_votes (in basis points)
-        let approval = ((claim.yes_votes as u128)
-            .checked_mul(10000)
-            .ok_or(ErrorCode::ArithmeticOverflow)?)
-            .checked_div(total_votes as u128)
-            .ok_or(ErrorCode::ArithmeticOverflow)? as u16;
-        
-        if approval >= pool.approval_ratio {
-            // Claim approved - pay out
-            claim.status = ClaimStatus::Approved;
-            require!(
-                ctx.accounts.pool_vault.amount >= claim.requested_amount,
-                ErrorCode::InsufficientPoolFunds
-            );
-            
-            // Transfer tokens to claimant
-            let seeds = &[
-                b"pool_authority",
-                pool.key().as_ref(),
-                &[ctx.bumps.pool_authority],
-            ];
-            let signer = &[&seeds[..]];
-            
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.pool_vault.to_account_info(),
-                to: ctx.accounts.claimant_token_account.to_account_info(),
-                authority: ctx.accounts.pool_authority.to_account_info(),
+        if total_votes < claim.quorum {
+            let oracle_decision = match (pool.decision_mode, claim.oracle_decision) {
+                (DecisionMode::OracleWithOverride, Some(approved)) => approved,
+                _ => return err!(ErrorCode::QuorumNotReached),
             };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-            token::transfer(cpi_ctx, claim.requested_amount)?;
-            
-            claim.status = ClaimStatus::Paid;
-            pool.total_paid_out = pool.total_paid_out.checked_add(claim.requested_amount).ok_or(ErrorCode::ArithmeticOverflow)?;
-            
-            msg!("Claim {} APPROVED and PAID {} tokens to {}", 
-                claim.claim_id, claim.requested_amount, claim.claimant);
+            if oracle_decision {
+                claim.approved_amount = claim.oracle_amount;
+            }
+            decide_claim(pool, &mut ctx.accounts.member, &mut ctx.accounts.policy, claim, oracle_decision, clock.unix_timestamp);
+            msg!("Claim {} {} by oracle, quorum not reached", 
+                claim.claim_id, if oracle_decision { "APPROVED" } else { "REJECTED" });
+            return Ok(());
+        }
+        
+        // Calculate share-weighted approval ratio: yes_weight / total_weight (in basis points)
+        let total_weight = (claim.yes_weight as u128)
+            .checked_add(claim.no_weight as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let approval = if total_weight == 0 {
+            0
+        } else {
+            ((claim.yes_weight as u128)
+                .checked_mul(10000)
+                .ok_or(ErrorCode::ArithmeticOverflow)?)
+                .checked_div(total_weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)? as u16
+        };
+        
+        // Approved claims are paid out by execute_payout
+        let approved = approval >= claim.approval_ratio;
+        decide_claim(pool, &mut ctx.accounts.member, &mut ctx.accounts.policy, claim, approved, clock.unix_timestamp);
+        
+        if approved {
+            msg!("Claim {} APPROVED - approval ratio {} >= required {}", 
+                claim.claim_id, approval, claim.approval_ratio);
         } else {
-            // Claim rejected
-            claim.status = ClaimStatus::Rejected;
             msg!("Claim {} REJECTED - approval ratio {} < required {}", 
-                claim.claim_id, approval, pool.approval_ratio);
+                claim.claim_id, approval, claim.approval_ratio);
         }
         
         Ok(())
     }
-}
-
-// ============ CONTEXTS ============
 
-#[derive(Accounts)]
-pub struct InitializePool<'info> {
-    #[account(
-        init,
-        payer = admin,
-        space = Pool::LEN,
-        seeds = [b"pool"],
-        bump
-    )]
-    pub pool: Account<'info, Pool>,
-    
-    pub accepted_mint: Account<'info, Mint>,
-    
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Configure the oracle authority and how claims are decided (admin only)
+    pub fn configure_oracle(
+        ctx: Context<ConfigureOracle>,
+        oracle: Pubkey,
+        decision_mode: DecisionMode,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.oracle = oracle;
+        pool.decision_mode = decision_mode;
+        require!(pool.has_decider(), ErrorCode::InvalidGovernanceConfig);
+        require!(pool.has_premium_decider(), ErrorCode::PremiumsRequireDecider);
+        
+        msg!("Oracle set to {}", oracle);
+        
+        Ok(())
+    }
 
-#[derive(Accounts)]
+    /// Approve or reject a pending claim as the pool oracle
+    pub fn oracle_decide_claim(ctx: Context<OracleDecideClaim>, approve: bool) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Pending, ErrorCode::ClaimNotPending);
+        
+        let clock = Clock::get()?;
+        let amount = claim.requested_amount;
+        apply_oracle_decision(pool, &mut ctx.accounts.member, &mut ctx.accounts.policy, claim, approve, amount, clock.unix_timestamp)
+    }
+
+    /// Register the keys allowed to sign off-chain assessments (admin only)
+    pub fn configure_assessors(ctx: Context<ConfigureAssessors>, assessors: Vec<Pubkey>) -> Result<()> {
+        require!(assessors.len() <= Pool::MAX_ASSESSORS, ErrorCode::TooManyAssessors);
+        
+        // Oracle modes need someone able to decide
+        let pool = &mut ctx.accounts.pool;
+        pool.assessors = assessors;
+        require!(pool.has_decider(), ErrorCode::InvalidGovernanceConfig);
+        
+        msg!("{} assessors registered", pool.assessors.len());
+        
+        Ok(())
+    }
+
+    /// Apply an assessment signed by a registered assessor
+    ///
+    /// The transaction must include an Ed25519 program instruction verifying the
+    /// assessor's signature over the serialized `Assessment`, immediately before this one.
+    pub fn submit_assessment(ctx: Context<SubmitAssessment>, assessment: Assessment) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Pending, ErrorCode::ClaimNotPending);
+        require!(
+            assessment.pool == pool.key() && assessment.claim_id == claim.claim_id,
+            ErrorCode::AssessmentMismatch
+        );
+        
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp <= assessment.expiry, ErrorCode::AssessmentExpired);
+        
+        // Check the preceding instruction verified an assessor's signature over this assessment
+        let message = assessment.try_to_vec()?;
+        let assessor = verified_ed25519_signer(&ctx.accounts.instructions, &message)?;
+        require!(pool.assessors.contains(&assessor), ErrorCode::UnknownAssessor);
+        
+        if assessment.approve {
+            require!(
+                assessment.approved_amount <= claim.requested_amount,
+                ErrorCode::InvalidApprovedAmount
+            );
+        }
+        
+        msg!("Assessment by {} for claim {}", assessor, claim.claim_id);
+        
+        // A claim leaves Pending (or records its oracle decision) once, so an assessment cannot be replayed
+        apply_oracle_decision(
+            pool,
+            &mut ctx.accounts.member,
+            &mut ctx.accounts.policy,
+            claim,
+            assessment.approve,
+            assessment.approved_amount,
+            clock.unix_timestamp,
+        )
+    }
+
+    /// Create the pool's M-of-N assessor committee (admin only)
+    pub fn create_committee(
+        ctx: Context<CreateCommittee>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(Committee::is_valid_config(&members, threshold), ErrorCode::InvalidCommitteeConfig);
+        
+        let committee = &mut ctx.accounts.committee;
+        committee.pool = ctx.accounts.pool.key();
+        committee.members = members;
+        committee.threshold = threshold;
+        committee.epoch = 0;
+        committee.bump = ctx.bumps.committee;
+        ctx.accounts.pool.committee_active = true;
+        
+        msg!("Committee created: {}-of-{}", threshold, committee.members.len());
+        
+        Ok(())
+    }
+
+    /// Replace the committee, signed by at least `threshold` current members
+    ///
+    /// Current member signers are passed as remaining accounts.
+    pub fn rotate_committee(
+        ctx: Context<RotateCommittee>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(Committee::is_valid_config(&members, threshold), ErrorCode::InvalidCommitteeConfig);
+        
+        let committee = &mut ctx.accounts.committee;
+        
+        // Count distinct current members that signed
+        let mut signed: u16 = 0;
+        for account in ctx.remaining_accounts.iter().filter(|account| account.is_signer) {
+            if let Some(index) = committee.member_index(account.key) {
+                signed |= 1 << index;
+            }
+        }
+        require!(
+            signed.count_ones() >= committee.threshold as u32,
+            ErrorCode::CommitteeThresholdNotMet
+        );
+        
+        committee.members = members;
+        committee.threshold = threshold;
+        committee.epoch = committee.epoch.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Committee rotated to {}-of-{}, epoch {}", 
+            threshold, committee.members.len(), committee.epoch);
+        
+        Ok(())
+    }
+
+    /// Enable or disable the assessor committee (admin only)
+    pub fn configure_committee(ctx: Context<ConfigureCommittee>, active: bool) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.committee_active = active;
+        require!(pool.has_decider(), ErrorCode::InvalidGovernanceConfig);
+        
+        msg!("Committee {}", if active { "enabled" } else { "disabled" });
+        
+        Ok(())
+    }
+
+    /// Record a committee member's attestation; once `threshold` agree, the decision is
+    /// applied like an oracle decision
+    pub fn attest_claim(ctx: Context<AttestClaim>, approve: bool) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let committee = &ctx.accounts.committee;
+        
+        require!(ctx.accounts.pool.committee_active, ErrorCode::CommitteeDisabled);
+        require!(ctx.accounts.pool.decision_mode != DecisionMode::VotesOnly, ErrorCode::OracleDisabled);
+        require!(claim.status == ClaimStatus::Pending, ErrorCode::ClaimNotPending);
+        let index = committee
+            .member_index(&ctx.accounts.attester.key())
+            .ok_or(ErrorCode::NotCommitteeMember)?;
+        
+        // Attestations from before a rotation no longer count
+        if claim.committee_epoch != committee.epoch {
+            claim.committee_epoch = committee.epoch;
+            claim.approve_attestations = 0;
+            claim.reject_attestations = 0;
+        }
+        
+        let bit = 1u16 << index;
+        require!(
+            (claim.approve_attestations | claim.reject_attestations) & bit == 0,
+            ErrorCode::AlreadyAttested
+        );
+        
+        let attestations = if approve {
+            claim.approve_attestations |= bit;
+            claim.approve_attestations
+        } else {
+            claim.reject_attestations |= bit;
+            claim.reject_attestations
+        };
+        
+        msg!("Committee member {} attested {} for claim {}", 
+            ctx.accounts.attester.key(), if approve { "APPROVAL" } else { "REJECTION" }, claim.claim_id);
+        
+        if attestations.count_ones() >= committee.threshold as u32 {
+            let clock = Clock::get()?;
+            let amount = claim.requested_amount;
+            msg!("Committee threshold reached for claim {}", claim.claim_id);
+            apply_oracle_decision(
+                &mut ctx.accounts.pool,
+                &mut ctx.accounts.member,
+                &mut ctx.accounts.policy,
+                claim,
+                approve,
+                amount,
+                clock.unix_timestamp,
+            )?;
+        }
+        
+        Ok(())
+    }
+
+    /// Create the payout schedule for an approved claim paid in installments (permissionless)
+    pub fn create_payout_schedule(ctx: Context<CreatePayoutSchedule>) -> Result<()> {
+        let claim = &ctx.accounts.claim;
+        let pool = &ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Approved, ErrorCode::ClaimNotApproved);
+        require!(pool.requires_installments(claim.approved_amount), ErrorCode::InstallmentsNotRequired);
+        
+        let schedule = &mut ctx.accounts.payout_schedule;
+        schedule.pool = pool.key();
+        schedule.claim = claim.key();
+        schedule.total_amount = claim.approved_amount;
+        schedule.released_amount = 0;
+        schedule.tranche_count = pool.installment_count;
+        schedule.tranches_released = 0;
+        schedule.start_ts = 0; // set when the payout starts
+        schedule.interval_secs = pool.installment_interval_secs;
+        schedule.bump = ctx.bumps.payout_schedule;
+        
+        msg!("Payout schedule created for claim {}: {} tranches", claim.claim_id, schedule.tranche_count);
+        
+        Ok(())
+    }
+
+    /// Pay out an approved claim once the payout timelock has passed (permissionless)
+    pub fn execute_payout(ctx: Context<ExecutePayout>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Approved, ErrorCode::ClaimNotApproved);
+        validate_payee(claim, &ctx.accounts.vendor, &ctx.accounts.payee_token_account)?;
+        
+        // Check payout timelock has passed
+        let clock = Clock::get()?;
+        let payable_ts = claim.approved_ts.checked_add(pool.payout_delay_secs).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(clock.unix_timestamp >= payable_ts, ErrorCode::PayoutTimelockActive);
+        
+        // While premiums are charged, a payout uses up one of the claimant's coverage credits
+        if pool.premium_per_month > 0 && pool.coverage_mint != Pubkey::default() {
+            let (Some(coverage_mint), Some(coverage_account), Some(token_2022_program)) = (
+                &ctx.accounts.coverage_mint,
+                &ctx.accounts.claimant_coverage_account,
+                &ctx.accounts.token_2022_program,
+            ) else {
+                return err!(ErrorCode::CoverageAccountsRequired);
+            };
+            // Credits only cover claims made while the premiums behind them were in force
+            require!(coverage_account.amount > 0, ErrorCode::NoCoverageCredit);
+            require!(
+                claim.created_ts < ctx.accounts.member.coverage_until,
+                ErrorCode::PremiumCoverageLapsed
+            );
+            burn_coverage_credits(
+                token_2022_program,
+                coverage_mint,
+                coverage_account,
+                &ctx.accounts.pool_authority,
+                pool.key(),
+                ctx.bumps.pool_authority,
+                1,
+            )?;
+        }
+        
+        // Large claims are paid out by release_payout from a schedule created beforehand
+        if pool.requires_installments(claim.approved_amount) {
+            let schedule = ctx.accounts.payout_schedule.as_mut().ok_or(ErrorCode::PayoutScheduleRequired)?;
+            schedule.start_ts = clock.unix_timestamp;
+            claim.status = ClaimStatus::Paying;
+            
+            msg!("Claim {}: {} tokens to be paid in {} installments", 
+                claim.claim_id, claim.approved_amount, pool.installment_count);
+            
+            return Ok(());
+        }
+        
+        // Pay out, pro-rata with earlier shortfalls if the vault is short
+        let owed = claim.approved_amount;
+        let total_owed = owed.checked_add(pool.total_shortfall).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let available = pool
+            .to_base_value(&claim.payout_mint, ctx.accounts.pool_vault.amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let amount = pro_rata_payout(owed, available, total_owed)?;
+        
+        // Transfer tokens to the claimant or their repair vendor, in the claim's payout mint
+        let tokens = pool.from_base_value(&claim.payout_mint, amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        if tokens > 0 {
+            let fee = pay_from_vault(
+                &ctx.accounts.token_program,
+                &ctx.accounts.pool_vault,
+                &ctx.accounts.payee_token_account,
+                &ctx.accounts.treasury,
+                &ctx.accounts.payout_mint,
+                &ctx.accounts.pool_authority,
+                pool.key(),
+                ctx.bumps.pool_authority,
+                tokens,
+                pool.payout_fee_bps,
+            )?;
+            let fee_value = pool.to_base_value(&claim.payout_mint, fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+            pool.fees_collected = pool.fees_collected.checked_add(fee_value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        record_payout(pool, claim, &mut ctx.accounts.policy, &mut ctx.accounts.member, amount, clock.unix_timestamp)?;
+        claim.shortfall_amount = owed.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_shortfall = pool.total_shortfall.checked_add(claim.shortfall_amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        if claim.shortfall_amount == 0 {
+            claim.status = ClaimStatus::Paid;
+            msg!("Claim {} PAID {} tokens to {}", 
+                claim.claim_id, amount, ctx.accounts.payee_token_account.owner);
+        } else {
+            claim.status = ClaimStatus::Paying;
+            msg!("Claim {} PAID {} tokens to {}, shortfall of {} owed by pool", 
+                claim.claim_id, amount, ctx.accounts.payee_token_account.owner, claim.shortfall_amount);
+        }
+        
+        Ok(())
+    }
+
+    /// Reject an approved claim before it is paid out (admin only)
+    pub fn veto_claim(ctx: Context<VetoClaim>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        
+        require!(claim.status == ClaimStatus::Approved, ErrorCode::ClaimNotApproved);
+        
+        claim.status = ClaimStatus::Rejected;
+        ctx.accounts.pool.release_exposure(claim.approved_amount);
+        ctx.accounts.member.release_pending(claim.approved_amount);
+        ctx.accounts.policy.release_pending(claim.approved_amount);
+        
+        msg!("Claim {} VETOED by admin", claim.claim_id);
+        
+        Ok(())
+    }
+
+    /// Configure the delay between claim approval and payout (admin only)
+    pub fn configure_payout_delay(ctx: Context<ConfigurePayoutDelay>, delay_secs: i64) -> Result<()> {
+        require!(delay_secs >= 0, ErrorCode::InvalidGovernanceConfig);
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.payout_delay_secs = delay_secs;
+        
+        msg!("Payout delay set to {}s", delay_secs);
+        
+        Ok(())
+    }
+
+    /// Configure installment payouts for large claims (admin only)
+    pub fn configure_installments(
+        ctx: Context<ConfigureInstallments>,
+        threshold: u64,
+        count: u8,
+        interval_secs: i64,
+    ) -> Result<()> {
+        require!(
+            threshold == 0 || (count > 1 && interval_secs > 0),
+            ErrorCode::InvalidGovernanceConfig
+        );
+
+        let pool = &mut ctx.accounts.pool;
+        pool.installment_threshold = threshold;
+        pool.installment_count = count;
+        pool.installment_interval_secs = interval_secs;
+
+        msg!("Installments configured: threshold: {}, tranches: {}, interval: {}s",
+            threshold, count, interval_secs);
+
+        Ok(())
+    }
+
+    /// Release all due tranches of a payout schedule (permissionless crank)
+    pub fn release_payout(ctx: Context<ReleasePayout>) -> Result<()> {
+        let schedule = &mut ctx.accounts.payout_schedule;
+        let claim = &mut ctx.accounts.claim;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Paying, ErrorCode::ClaimNotPaying);
+        validate_payee(claim, &ctx.accounts.vendor, &ctx.accounts.payee_token_account)?;
+        
+        // Work out how much has vested since the last release
+        let clock = Clock::get()?;
+        let due = schedule.tranches_due(clock.unix_timestamp);
+        require!(due > schedule.tranches_released, ErrorCode::NoTrancheDue);
+        
+        let amount = schedule
+            .cumulative_amount(due)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_sub(schedule.released_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        // Pay the tranche pro-rata with earlier shortfalls if the vault is short
+        let total_owed = amount.checked_add(pool.total_shortfall).ok_or(ErrorCode::ArithmeticOverflow)?;
+        let available = pool
+            .to_base_value(&claim.payout_mint, ctx.accounts.pool_vault.amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let paid = pro_rata_payout(amount, available, total_owed)?;
+        let tokens = pool.from_base_value(&claim.payout_mint, paid).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        if tokens > 0 {
+            let fee = pay_from_vault(
+                &ctx.accounts.token_program,
+                &ctx.accounts.pool_vault,
+                &ctx.accounts.payee_token_account,
+                &ctx.accounts.treasury,
+                &ctx.accounts.payout_mint,
+                &ctx.accounts.pool_authority,
+                pool.key(),
+                ctx.bumps.pool_authority,
+                tokens,
+                pool.payout_fee_bps,
+            )?;
+            let fee_value = pool.to_base_value(&claim.payout_mint, fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+            pool.fees_collected = pool.fees_collected.checked_add(fee_value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        schedule.released_amount = schedule.released_amount.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        schedule.tranches_released = due;
+        record_payout(pool, claim, &mut ctx.accounts.policy, &mut ctx.accounts.member, paid, clock.unix_timestamp)?;
+        
+        // Whatever the vault could not cover is owed as a shortfall
+        let shortfall = amount.checked_sub(paid).ok_or(ErrorCode::ArithmeticOverflow)?;
+        claim.shortfall_amount = claim.shortfall_amount.checked_add(shortfall).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_shortfall = pool.total_shortfall.checked_add(shortfall).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        if claim.paid_amount == claim.approved_amount {
+            claim.status = ClaimStatus::Paid;
+        }
+        
+        msg!("Claim {} released {} tokens ({}/{} tranches), shortfall of {} owed by pool", 
+            claim.claim_id, paid, schedule.tranches_released, schedule.tranche_count, claim.shortfall_amount);
+        
+        Ok(())
+    }
+
+    /// Pay down a claim's shortfall from newly available vault funds (permissionless crank)
+    pub fn settle_shortfall(ctx: Context<SettleShortfall>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Paying, ErrorCode::ClaimNotPaying);
+        require!(claim.shortfall_amount > 0, ErrorCode::NoShortfall);
+        validate_payee(claim, &ctx.accounts.vendor, &ctx.accounts.payee_token_account)?;
+        
+        // Share the vault pro-rata among all claims still owed a shortfall
+        let available = pool
+            .to_base_value(&claim.payout_mint, ctx.accounts.pool_vault.amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let amount = pro_rata_payout(claim.shortfall_amount, available, pool.total_shortfall)?;
+        let tokens = pool.from_base_value(&claim.payout_mint, amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(tokens > 0, ErrorCode::InsufficientPoolFunds);
+        
+        let fee = pay_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_vault,
+            &ctx.accounts.payee_token_account,
+            &ctx.accounts.treasury,
+            &ctx.accounts.payout_mint,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            tokens,
+            pool.payout_fee_bps,
+        )?;
+        let fee_value = pool.to_base_value(&claim.payout_mint, fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.fees_collected = pool.fees_collected.checked_add(fee_value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        record_payout(pool, claim, &mut ctx.accounts.policy, &mut ctx.accounts.member, amount, now)?;
+        claim.shortfall_amount = claim.shortfall_amount.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_shortfall = pool.total_shortfall.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Installment claims stay Paying until every tranche has been released
+        if claim.paid_amount == claim.approved_amount {
+            claim.status = ClaimStatus::Paid;
+        }
+        
+        msg!("Claim {} shortfall settled by {} tokens, {} still owed", 
+            claim.claim_id, amount, claim.shortfall_amount);
+        
+        Ok(())
+    }
+
+    /// Create the backstop vault that covers claim shortfalls (admin only)
+    pub fn create_backstop(ctx: Context<CreateBackstop>) -> Result<()> {
+        msg!("Backstop vault {} created", ctx.accounts.backstop.key());
+        
+        Ok(())
+    }
+
+    /// Set the share of premiums paid to the backstop (admin only)
+    pub fn configure_backstop(ctx: Context<ConfigureBackstop>, premium_share_bps: u16) -> Result<()> {
+        require!(premium_share_bps <= Pool::MAX_BACKSTOP_PREMIUM_BPS, ErrorCode::InvalidBackstopConfig);
+        
+        ctx.accounts.pool.backstop_premium_bps = premium_share_bps;
+        
+        msg!("Backstop premium share set to {} bps", premium_share_bps);
+        
+        Ok(())
+    }
+
+    /// Add reinsurance capital to the backstop vault
+    pub fn fund_backstop(ctx: Context<FundBackstop>, amount: u64) -> Result<()> {
+        let received = transfer_to_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.funder_token_account,
+            &mut ctx.accounts.backstop,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.funder,
+            amount,
+        )?;
+        
+        msg!("Backstop funded with {} tokens by {}", received, ctx.accounts.funder.key());
+        
+        Ok(())
+    }
+
+    /// Cover a claim's shortfall from the backstop vault (permissionless crank)
+    pub fn draw_backstop(ctx: Context<DrawBackstop>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(claim.status == ClaimStatus::Paying, ErrorCode::ClaimNotPaying);
+        require!(claim.shortfall_amount > 0, ErrorCode::NoShortfall);
+        validate_payee(claim, &ctx.accounts.vendor, &ctx.accounts.payee_token_account)?;
+        
+        let amount = claim.shortfall_amount.min(ctx.accounts.backstop.amount);
+        require!(amount > 0, ErrorCode::BackstopEmpty);
+        
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.backstop,
+            &ctx.accounts.payee_token_account,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            amount,
+        )?;
+        
+        // Backstop funds are not member or underwriter value, so only the claim totals move
+        let now = Clock::get()?.unix_timestamp;
+        record_claim_payment(pool, claim, &mut ctx.accounts.policy, &mut ctx.accounts.member, amount, now)?;
+        claim.shortfall_amount = claim.shortfall_amount.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_shortfall = pool.total_shortfall.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_backstop_draws = pool.total_backstop_draws.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Installment claims stay Paying until every tranche has been released
+        if claim.paid_amount == claim.approved_amount {
+            claim.status = ClaimStatus::Paid;
+        }
+        
+        msg!("Claim {} drew {} tokens from the backstop, {} still owed", 
+            claim.claim_id, amount, claim.shortfall_amount);
+        
+        Ok(())
+    }
+
+    /// Create the fee treasury for an accepted mint (admin only)
+    pub fn create_treasury(ctx: Context<CreateTreasury>) -> Result<()> {
+        msg!("Treasury {} created for mint {}", ctx.accounts.treasury.key(), ctx.accounts.mint.key());
+        
+        Ok(())
+    }
+
+    /// Configure deposit, premium and payout fees (admin only)
+    pub fn configure_fees(
+        ctx: Context<ConfigureFees>,
+        deposit_fee_bps: u16,
+        premium_fee_bps: u16,
+        payout_fee_bps: u16,
+    ) -> Result<()> {
+        require!(
+            deposit_fee_bps <= Pool::MAX_FEE_BPS
+                && premium_fee_bps <= Pool::MAX_FEE_BPS
+                && payout_fee_bps <= Pool::MAX_FEE_BPS,
+            ErrorCode::InvalidFeeConfig
+        );
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.deposit_fee_bps = deposit_fee_bps;
+        pool.premium_fee_bps = premium_fee_bps;
+        pool.payout_fee_bps = payout_fee_bps;
+        
+        msg!("Fees set: deposit {}, premium {}, payout {} bps", 
+            deposit_fee_bps, premium_fee_bps, payout_fee_bps);
+        
+        Ok(())
+    }
+
+    /// Withdraw collected fees from a treasury (admin only)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        require!(ctx.accounts.treasury.amount >= amount, ErrorCode::InsufficientPoolFunds);
+        
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.treasury,
+            &ctx.accounts.destination,
+            &ctx.accounts.mint,
+            &ctx.accounts.pool_authority,
+            ctx.accounts.pool.key(),
+            ctx.bumps.pool_authority,
+            amount,
+        )?;
+        
+        msg!("Admin withdrew {} fees of {}", amount, ctx.accounts.mint.key());
+        
+        Ok(())
+    }
+
+    /// Configure the yield strategy program and the minimum liquid reserve (admin only)
+    pub fn configure_strategy(
+        ctx: Context<ConfigureStrategy>,
+        strategy_program: Pubkey,
+        min_liquid_reserve_bps: u16,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(min_liquid_reserve_bps <= 10000, ErrorCode::InvalidStrategyConfig);
+        require!(
+            pool.strategy_deployed == 0 || strategy_program == pool.strategy_program,
+            ErrorCode::StrategyFundsDeployed
+        );
+        
+        pool.strategy_program = strategy_program;
+        pool.min_liquid_reserve_bps = min_liquid_reserve_bps;
+        
+        msg!("Strategy set to {}, min liquid reserve {} bps", strategy_program, min_liquid_reserve_bps);
+        
+        Ok(())
+    }
+
+    /// Deploy idle vault funds to the strategy, keeping the minimum liquid reserve (admin only)
+    pub fn deploy_to_strategy<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeployToStrategy<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        
+        // Funds left in the vault must cover the reserve share of all primary mint funds
+        let liquid = ctx.accounts.pool_vault.amount.checked_sub(amount).ok_or(ErrorCode::InsufficientPoolFunds)?;
+        let total = ctx.accounts.pool_vault.amount.checked_add(pool.strategy_deployed).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            liquid as u128 * 10000 >= total as u128 * pool.min_liquid_reserve_bps as u128,
+            ErrorCode::LiquidReserveBreached
+        );
+        
+        // Asset vaults come first in remaining accounts, for the reserve check, then the strategy's accounts
+        require!(ctx.remaining_accounts.len() >= pool.assets.len(), ErrorCode::InvalidAssetVault);
+        let (asset_vaults, strategy_accounts) = ctx.remaining_accounts.split_at(pool.assets.len());
+        
+        // The strategy only sees the escrow, funded with exactly the amount being deployed
+        transfer_from_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pool_vault,
+            &ctx.accounts.strategy_escrow,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.pool_authority,
+            pool.key(),
+            ctx.bumps.pool_authority,
+            amount,
+        )?;
+        ctx.accounts.strategy_escrow.reload()?;
+        let escrow_before = ctx.accounts.strategy_escrow.amount;
+        strategy_cpi(
+            "deposit",
+            &ctx.accounts.strategy_program,
+            &ctx.accounts.strategy_escrow,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.strategy_authority,
+            &ctx.accounts.token_program,
+            strategy_accounts,
+            pool.key(),
+            ctx.bumps.strategy_authority,
+            amount,
+        )?;
+        ctx.accounts.strategy_escrow.reload()?;
+        let sent = escrow_before.checked_sub(ctx.accounts.strategy_escrow.amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(sent == amount, ErrorCode::StrategyDepositMismatch);
+        pool.strategy_deployed = pool.strategy_deployed.checked_add(sent).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // The vaults must still cover the reserve after the strategy call
+        ctx.accounts.pool_vault.reload()?;
+        check_reserve(pool, &ctx.accounts.pool_authority.key(), ctx.accounts.pool_vault.amount, asset_vaults)?;
+        
+        msg!("Deployed {} tokens to strategy, {} now deployed", sent, pool.strategy_deployed);
+        
+        Ok(())
+    }
+
+    /// Recall funds from the strategy into the vault, crediting any yield to members (admin only)
+    pub fn recall_from_strategy<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecallFromStrategy<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        
+        let escrow_before = ctx.accounts.strategy_escrow.amount;
+        strategy_cpi(
+            "withdraw",
+            &ctx.accounts.strategy_program,
+            &ctx.accounts.strategy_escrow,
+            &ctx.accounts.accepted_mint,
+            &ctx.accounts.strategy_authority,
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+            pool.key(),
+            ctx.bumps.strategy_authority,
+            amount,
+        )?;
+        ctx.accounts.strategy_escrow.reload()?;
+        let received = ctx.accounts.strategy_escrow.amount.checked_sub(escrow_before).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Move the returned funds out of the escrow into the vault
+        if received > 0 {
+            transfer_from_strategy_escrow(
+                &ctx.accounts.token_program,
+                &ctx.accounts.strategy_escrow,
+                &ctx.accounts.pool_vault,
+                &ctx.accounts.accepted_mint,
+                &ctx.accounts.strategy_authority,
+                pool.key(),
+                ctx.bumps.strategy_authority,
+                received,
+            )?;
+        }
+        
+        // Anything beyond the deployed principal is yield
+        let yield_amount = received.saturating_sub(pool.strategy_deployed);
+        pool.strategy_deployed = pool.strategy_deployed.saturating_sub(received);
+        pool.total_deposits = pool.total_deposits.checked_add(yield_amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Recalled {} tokens from strategy ({} yield), {} still deployed", 
+            received, yield_amount, pool.strategy_deployed);
+        
+        Ok(())
+    }
+
+    /// Write off deployed funds the strategy can no longer return (admin only)
+    pub fn recognize_strategy_loss(ctx: Context<RecognizeStrategyLoss>, amount: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        
+        require!(amount <= pool.strategy_deployed, ErrorCode::StrategyLossExceedsDeployed);
+        pool.strategy_deployed = pool.strategy_deployed.checked_sub(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Underwriters absorb the loss first, like a claim payout
+        let uncovered = pool.draw_underwriter_funds(amount);
+        pool.total_deposits = pool.total_deposits.saturating_sub(uncovered);
+        
+        msg!("Wrote off {} tokens lost by the strategy, {} still deployed", amount, pool.strategy_deployed);
+        
+        Ok(())
+    }
+
+    /// Accept an additional mint for deposits and payouts (admin only)
+    pub fn add_asset(ctx: Context<ConfigureAsset>, rate_bps: u32) -> Result<()> {
+        require!(rate_bps > 0, ErrorCode::InvalidAssetRate);
+        validate_accepted_mint(&ctx.accounts.asset_mint)?;
+        
+        let pool = &mut ctx.accounts.pool;
+        let mint = ctx.accounts.asset_mint.key();
+        require!(!pool.is_accepted(&mint), ErrorCode::AssetAlreadyAccepted);
+        require!(pool.assets.len() < Pool::MAX_ASSETS, ErrorCode::TooManyAssets);
+        
+        pool.assets.push(AcceptedAsset {
+            mint,
+            decimals: ctx.accounts.asset_mint.decimals,
+            rate_bps,
+        });
+        
+        msg!("Asset {} accepted at rate {}", mint, rate_bps);
+        
+        Ok(())
+    }
+
+    /// Update the rate of an additional accepted mint (admin only)
+    pub fn set_asset_rate(ctx: Context<ConfigureAsset>, rate_bps: u32) -> Result<()> {
+        require!(rate_bps > 0, ErrorCode::InvalidAssetRate);
+        
+        let mint = ctx.accounts.asset_mint.key();
+        let asset = ctx.accounts.pool
+            .assets
+            .iter_mut()
+            .find(|asset| asset.mint == mint)
+            .ok_or(ErrorCode::UnsupportedAsset)?;
+        asset.rate_bps = rate_bps;
+        
+        msg!("Asset {} rate set to {}", mint, rate_bps);
+        
+        Ok(())
+    }
+
+    /// Set the minimum reserve withdrawals must leave, relative to total claim limits (admin only)
+    pub fn configure_reserve(ctx: Context<ConfigureReserve>, min_reserve_bps: u16) -> Result<()> {
+        require!(min_reserve_bps <= 10000, ErrorCode::InvalidReserveConfig);
+        ctx.accounts.pool.min_reserve_bps = min_reserve_bps;
+        
+        msg!("Minimum reserve set to {} bps of total claim limits", min_reserve_bps);
+        
+        Ok(())
+    }
+
+    /// Configure the minimum deposit, per-member maximum and pool capacity (admin only)
+    pub fn configure_deposit_limits(
+        ctx: Context<ConfigureDepositLimits>,
+        min_deposit: u64,
+        max_deposit_per_member: u64,
+        pool_capacity: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.min_deposit = min_deposit;
+        pool.max_deposit_per_member = max_deposit_per_member;
+        pool.pool_capacity = pool_capacity;
+        
+        msg!("Deposit limits set: min {}, per member {}, capacity {}", 
+            min_deposit, max_deposit_per_member, pool_capacity);
+        
+        Ok(())
+    }
+
+    /// Configure the period over which deposits vest into claim limits (admin only)
+    pub fn configure_claim_vesting(ctx: Context<ConfigureClaimVesting>, vesting_secs: i64) -> Result<()> {
+        require!(vesting_secs >= 0, ErrorCode::InvalidGovernanceConfig);
+        
+        ctx.accounts.pool.claim_vesting_secs = vesting_secs;
+        
+        msg!("Claim limits vest over {} seconds", vesting_secs);
+        
+        Ok(())
+    }
+
+    /// Configure the claim period and the lifetime payout cap per member (admin only)
+    pub fn configure_claim_caps(
+        ctx: Context<ConfigureClaimCaps>,
+        claim_period_secs: i64,
+        lifetime_payout_cap: u64,
+    ) -> Result<()> {
+        require!(claim_period_secs > 0, ErrorCode::InvalidGovernanceConfig);
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.claim_period_secs = claim_period_secs;
+        pool.lifetime_payout_cap = lifetime_payout_cap;
+        
+        msg!("Claim period set to {} seconds, lifetime cap {}", claim_period_secs, lifetime_payout_cap);
+        
+        Ok(())
+    }
+
+    /// Set the Merkle root of wallets allowed to join, or all zeros to open the pool (admin only)
+    pub fn configure_allowlist(ctx: Context<ConfigureAllowlist>, root: [u8; 32]) -> Result<()> {
+        ctx.accounts.pool.allowlist_root = root;
+        
+        msg!("Allowlist root set, membership {}", if root == [0; 32] { "open" } else { "gated" });
+        
+        Ok(())
+    }
+
+    /// Configure the coverage term of newly registered device policies (admin only)
+    pub fn configure_policy_term(ctx: Context<ConfigurePolicyTerm>, term_secs: i64) -> Result<()> {
+        require!(term_secs > 0, ErrorCode::InvalidGovernanceConfig);
+        
+        let pool = &mut ctx.accounts.pool;
+        pool.policy_term_secs = term_secs;
+        
+        msg!("Policy term set to {}s", term_secs);
+        
+        Ok(())
+    }
+
+    /// Configure the largest declared value a device can be insured for (admin only)
+    pub fn configure_max_declared_value(ctx: Context<ConfigureMaxDeclaredValue>, max_declared_value: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.max_declared_value = max_declared_value;
+        require!(
+            pool.premium_per_month == 0 || max_declared_value > 0,
+            ErrorCode::InvalidGovernanceConfig
+        );
+        
+        msg!("Max declared value set to {}", max_declared_value);
+        
+        Ok(())
+    }
+
+    /// Configure monthly premiums and the paid streak required to claim (admin only)
+    pub fn configure_premiums(
+        ctx: Context<ConfigurePremiums>,
+        premium_per_month: u64,
+        min_months_for_claim: u8,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.premium_per_month = premium_per_month;
+        pool.min_months_for_claim = min_months_for_claim;
+        
+        // Policyholders claim up to their declared value, so premium pools need it capped
+        require!(
+            premium_per_month == 0 || pool.max_declared_value > 0,
+            ErrorCode::InvalidGovernanceConfig
+        );
+        require!(pool.has_premium_decider(), ErrorCode::PremiumsRequireDecider);
+        
+        msg!("Premium set to {} per month, {} months required to claim", 
+            premium_per_month, min_months_for_claim);
+        
+        Ok(())
+    }
+
+    /// Register a repair vendor that claims can be paid out to (admin only)
+    pub fn register_vendor(ctx: Context<RegisterVendor>) -> Result<()> {
+        let vendor = &mut ctx.accounts.vendor;
+        
+        vendor.pool = ctx.accounts.pool.key();
+        vendor.wallet = ctx.accounts.vendor_wallet.key();
+        vendor.active = true;
+        vendor.bump = ctx.bumps.vendor;
+        
+        msg!("Vendor {} registered", vendor.wallet);
+        
+        Ok(())
+    }
+
+    /// Enable or disable payouts to a registered vendor (admin only)
+    pub fn set_vendor_active(ctx: Context<SetVendorActive>, active: bool) -> Result<()> {
+        let vendor = &mut ctx.accounts.vendor;
+        vendor.active = active;
+        
+        msg!("Vendor {} active: {}", vendor.wallet, active);
+        
+        Ok(())
+    }
+}
+
+/// Check that the payee token account belongs to the claim's vendor, or the claimant if none
+fn validate_payee(
+    claim: &Claim,
+    vendor: &Option<Account<Vendor>>,
+    payee_token_account: &InterfaceAccount<TokenAccount>,
+) -> Result<()> {
+    let payee = match (claim.vendor, vendor) {
+        (Some(vendor_key), Some(vendor)) => {
+            require_keys_eq!(vendor.key(), vendor_key, ErrorCode::InvalidVendor);
+            require!(vendor.active, ErrorCode::VendorNotActive);
+            vendor.wallet
+        }
+        (None, None) => claim.claimant,
+        _ => return err!(ErrorCode::InvalidVendor),
+    };
+    require_keys_eq!(payee_token_account.owner, payee, ErrorCode::InvalidPayee);
+    Ok(())
+}
+
+/// Approve or reject a pending claim, releasing the exposure and the pending amounts on the
+/// claimant and device policy it no longer carries
+fn decide_claim(pool: &mut Pool, member: &mut Member, policy: &mut Policy, claim: &mut Claim, approved: bool, now: i64) {
+    claim.decide(approved, now);
+    let remaining = if approved { claim.approved_amount } else { 0 };
+    let released = claim.requested_amount.saturating_sub(remaining);
+    pool.release_exposure(released);
+    member.release_pending(released);
+    policy.release_pending(released);
+}
+
+/// Apply an oracle or assessor decision to a pending claim according to the pool's decision mode;
+/// `approved_amount` replaces the requested amount only once the decision takes effect
+fn apply_oracle_decision(
+    pool: &mut Pool,
+    member: &mut Member,
+    policy: &mut Policy,
+    claim: &mut Claim,
+    approve: bool,
+    approved_amount: u64,
+    now: i64,
+) -> Result<()> {
+    match pool.decision_mode {
+        DecisionMode::VotesOnly => return err!(ErrorCode::OracleDisabled),
+        DecisionMode::OracleOnly => {
+            if approve {
+                claim.approved_amount = approved_amount;
+            }
+            decide_claim(pool, member, policy, claim, approve, now);
+            msg!("Claim {} {} by oracle", 
+                claim.claim_id, if approve { "APPROVED" } else { "REJECTED" });
+        }
+        DecisionMode::OracleWithOverride => {
+            // Recorded now, applied by finalize_claim unless members override
+            require!(claim.oracle_decision.is_none(), ErrorCode::OracleAlreadyDecided);
+            claim.oracle_decision = Some(approve);
+            claim.oracle_amount = approved_amount;
+            msg!("Oracle recommends {} for claim {}", 
+                if approve { "APPROVAL" } else { "REJECTION" }, claim.claim_id);
+        }
+    }
+    Ok(())
+}
+
+/// Signer of the Ed25519 program instruction preceding the current one, which must verify
+/// exactly one signature over `expected_message` with all data inline
+fn verified_ed25519_signer(instructions: &UncheckedAccount, expected_message: &[u8]) -> Result<Pubkey> {
+    // Ed25519 instruction layout: count (u8), padding (u8), then one 14-byte offsets struct
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const PUBKEY_LEN: usize = 32;
+    
+    let current_index = load_current_index_checked(instructions)?;
+    require!(current_index > 0, ErrorCode::InvalidAssessmentSignature);
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions)?;
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        ErrorCode::InvalidAssessmentSignature
+    );
+    
+    let data = &ix.data;
+    require!(
+        data.len() >= OFFSETS_START + OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidAssessmentSignature
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[OFFSETS_START + at], data[OFFSETS_START + at + 1]]);
+    let signature_ix_index = read_u16(2);
+    let pubkey_offset = read_u16(4) as usize;
+    let pubkey_ix_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_ix_index = read_u16(12);
+    
+    // Signature, key and message must all live in the Ed25519 instruction itself
+    require!(
+        signature_ix_index == u16::MAX && pubkey_ix_index == u16::MAX && message_ix_index == u16::MAX,
+        ErrorCode::InvalidAssessmentSignature
+    );
+    
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+        .ok_or(ErrorCode::InvalidAssessmentSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidAssessmentSignature)?;
+    require!(message == expected_message, ErrorCode::AssessmentMismatch);
+    
+    Pubkey::try_from(pubkey).map_err(|_| error!(ErrorCode::InvalidAssessmentSignature))
+}
+
+/// Check a Merkle proof of `leaf` against `root`, hashing each pair in sorted order
+fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
+
+/// Reject deposits worth `value` that would take the member's shares, now worth `held`,
+/// over the per-member maximum or the pool over its capacity
+fn check_deposit_limits(pool: &Pool, held: u64, value: u64) -> Result<()> {
+    if pool.max_deposit_per_member > 0 {
+        let position = held.checked_add(value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(position <= pool.max_deposit_per_member, ErrorCode::MemberDepositCapExceeded);
+    }
+    if pool.pool_capacity > 0 {
+        let total = pool.total_deposits.checked_add(value).ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(total <= pool.pool_capacity, ErrorCode::PoolCapacityExceeded);
+    }
+    Ok(())
+}
+
+/// Require the pool to hold its minimum reserve: `base_left` in the primary vault, funds deployed to the
+/// strategy and the asset vaults passed in `pool.assets` order (vaults left out count as empty)
+fn check_reserve(pool: &Pool, pool_authority: &Pubkey, base_left: u64, asset_vaults: &[AccountInfo]) -> Result<()> {
+    let required = pool.required_reserve();
+    if required == 0 {
+        return Ok(());
+    }
+    require!(asset_vaults.len() <= pool.assets.len(), ErrorCode::InvalidAssetVault);
+    
+    let mut funds = base_left.checked_add(pool.strategy_deployed).ok_or(ErrorCode::ArithmeticOverflow)?;
+    for (info, asset) in asset_vaults.iter().zip(pool.assets.iter()) {
+        require!(
+            *info.owner == anchor_spl::token::ID || *info.owner == token_2022::ID,
+            ErrorCode::InvalidAssetVault
+        );
+        let vault_address = get_associated_token_address_with_program_id(pool_authority, &asset.mint, info.owner);
+        require_keys_eq!(info.key(), vault_address, ErrorCode::InvalidAssetVault);
+        
+        let vault = TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        let value = pool.to_base_value(&asset.mint, vault.amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        funds = funds.checked_add(value).ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+    require!(funds >= required, ErrorCode::ReserveRatioBreached);
+    Ok(())
+}
+
+/// Take a withdrawal of `value` out of member and pool totals and refresh the member's claim limit
+fn record_withdrawal(pool: &mut Pool, member: &mut Member, value: u64, shares_left: u64, supply_left: u64) -> Result<()> {
+    member.deposited_amount = member.deposited_amount.saturating_sub(value);
+    pool.total_deposits = pool.total_deposits.checked_sub(value).ok_or(ErrorCode::ArithmeticOverflow)?;
+    
+    let remaining_value = value_of_shares(shares_left, supply_left, pool.total_deposits)?;
+    let claim_limit = pool.claim_limit_for(remaining_value);
+    require!(claim_limit >= member.pending_claims, ErrorCode::PendingClaimsExist);
+    member.claim_limit = claim_limit;
+    
+    // Withdrawals come out of vested value first
+    member.clamp_vesting(remaining_value, Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+/// Record a payout from the pool vault against the pool, claim, device policy and member totals
+fn record_payout(
+    pool: &mut Pool,
+    claim: &mut Claim,
+    policy: &mut Policy,
+    member: &mut Member,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    record_claim_payment(pool, claim, policy, member, amount, now)?;
+    
+    // Underwriters absorb claim losses first; anything beyond their funds is shared by member shares
+    let uncovered = pool.draw_underwriter_funds(amount);
+    pool.total_deposits = pool.total_deposits.saturating_sub(uncovered);
+    Ok(())
+}
+
+/// Record a payment on a claim against the pool, claim, device policy and member totals
+fn record_claim_payment(
+    pool: &mut Pool,
+    claim: &mut Claim,
+    policy: &mut Policy,
+    member: &mut Member,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    // The payment was reserved on the policy when the claim was submitted
+    policy.release_pending(amount);
+    require!(amount <= policy.remaining_coverage(), ErrorCode::PolicyCapExceeded);
+    
+    member.record_claimed(amount, claim.paid_amount == 0, now, pool.claim_period_secs);
+    policy.total_paid = policy.total_paid.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+    claim.paid_amount = claim.paid_amount.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.total_paid_out = pool.total_paid_out.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.release_exposure(amount);
+    Ok(())
+}
+
+/// Shares to mint for depositing `amount` into a share class worth `total_value`
+fn shares_for_amount(amount: u64, total_shares: u64, total_value: u64) -> Result<u64> {
+    if total_shares == 0 {
+        return Ok(amount);
+    }
+    // Shares left worthless by losses are diluted to a negligible stake rather than blocking deposits
+    let shares = (amount as u128)
+        .checked_mul(total_shares as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(total_value.max(1) as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    u64::try_from(shares).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Value of `shares` out of `total_shares` in a share class worth `total_value`
+fn value_of_shares(shares: u64, total_shares: u64, total_value: u64) -> Result<u64> {
+    if total_shares == 0 {
+        return Ok(0);
+    }
+    let value = (shares as u128)
+        .checked_mul(total_value as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(total_shares as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Shares to burn, rounded up, for withdrawing `amount` from a share class worth `total_value`
+fn shares_to_burn(amount: u64, total_shares: u64, total_value: u64) -> Result<u64> {
+    require!(total_value > 0, ErrorCode::SharesWorthless);
+    let shares = (amount as u128)
+        .checked_mul(total_shares as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .div_ceil(total_value as u128);
+    u64::try_from(shares).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Amount payable on `owed` when `available` funds are shared pro-rata across `total_owed`
+fn pro_rata_payout(owed: u64, available: u64, total_owed: u64) -> Result<u64> {
+    if available >= total_owed {
+        return Ok(owed);
+    }
+    let amount = (owed as u128)
+        .checked_mul(available as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(total_owed as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)? as u64;
+    Ok(amount)
+}
+
+/// Transfer tokens out of the pool vault, signed by the pool authority PDA
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    pool_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    pool_authority: &UncheckedAccount<'info>,
+    pool_key: Pubkey,
+    pool_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"pool_authority",
+        pool_key.as_ref(),
+        &[pool_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = TransferChecked {
+        from: pool_vault.to_account_info(),
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: pool_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+/// Move funds returned by the strategy from its escrow back into the pool vault
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_strategy_escrow<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    strategy_escrow: &InterfaceAccount<'info, TokenAccount>,
+    pool_vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    strategy_authority: &UncheckedAccount<'info>,
+    pool_key: Pubkey,
+    strategy_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"strategy_authority",
+        pool_key.as_ref(),
+        &[strategy_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = TransferChecked {
+        from: strategy_escrow.to_account_info(),
+        mint: mint.to_account_info(),
+        to: pool_vault.to_account_info(),
+        authority: strategy_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+/// Burn coverage credits from a member's account; the pool authority is the mint's
+/// permanent delegate, so no member signature is needed
+fn burn_coverage_credits<'info>(
+    token_2022_program: &Program<'info, Token2022>,
+    coverage_mint: &InterfaceAccount<'info, Mint>,
+    coverage_account: &InterfaceAccount<'info, TokenAccount>,
+    pool_authority: &UncheckedAccount<'info>,
+    pool_key: Pubkey,
+    pool_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"pool_authority",
+        pool_key.as_ref(),
+        &[pool_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = token_2022::Burn {
+        mint: coverage_mint.to_account_info(),
+        from: coverage_account.to_account_info(),
+        authority: pool_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_2022_program.to_account_info(), cpi_accounts, signer);
+    token_2022::burn(cpi_ctx, amount)
+}
+
+/// Transfer tokens into the pool vault, returning the amount the vault received
+fn transfer_to_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    pool_vault: &mut InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &Signer<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let balance_before = pool_vault.amount;
+    
+    let cpi_accounts = TransferChecked {
+        from: from.to_account_info(),
+        mint: mint.to_account_info(),
+        to: pool_vault.to_account_info(),
+        authority: authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
+    
+    pool_vault.reload()?;
+    let received = pool_vault.amount.checked_sub(balance_before).ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(received)
+}
+
+/// Transfer the `fee_bps` fee on `amount` from the payer straight to the treasury; returns the fee
+fn collect_fee_from<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    treasury: &Option<InterfaceAccount<'info, TokenAccount>>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &Signer<'info>,
+    amount: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    let fee = bps_of(amount, fee_bps)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+    
+    let treasury = treasury.as_ref().ok_or(ErrorCode::TreasuryRequired)?;
+    let cpi_accounts = TransferChecked {
+        from: from.to_account_info(),
+        mint: mint.to_account_info(),
+        to: treasury.to_account_info(),
+        authority: authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+    token_interface::transfer_checked(cpi_ctx, fee, mint.decimals)?;
+    Ok(fee)
+}
+
+/// `bps` basis points of `amount`, rounded down
+fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / 10000;
+    u64::try_from(share).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Move `fee_bps` of `amount` from the vault to the treasury, returning the fee taken
+#[allow(clippy::too_many_arguments)]
+fn collect_fee<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    pool_vault: &InterfaceAccount<'info, TokenAccount>,
+    treasury: &Option<InterfaceAccount<'info, TokenAccount>>,
+    mint: &InterfaceAccount<'info, Mint>,
+    pool_authority: &UncheckedAccount<'info>,
+    pool_key: Pubkey,
+    pool_authority_bump: u8,
+    amount: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    let fee = bps_of(amount, fee_bps)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+    
+    let treasury = treasury.as_ref().ok_or(ErrorCode::TreasuryRequired)?;
+    transfer_from_vault(token_program, pool_vault, treasury, mint, pool_authority, pool_key, pool_authority_bump, fee)?;
+    Ok(fee)
+}
+
+/// Pay `amount` out of the vault, withholding the payout fee for the treasury; returns the fee
+#[allow(clippy::too_many_arguments)]
+fn pay_from_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    pool_vault: &InterfaceAccount<'info, TokenAccount>,
+    payee: &InterfaceAccount<'info, TokenAccount>,
+    treasury: &Option<InterfaceAccount<'info, TokenAccount>>,
+    mint: &InterfaceAccount<'info, Mint>,
+    pool_authority: &UncheckedAccount<'info>,
+    pool_key: Pubkey,
+    pool_authority_bump: u8,
+    amount: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    let fee = collect_fee(
+        token_program,
+        pool_vault,
+        treasury,
+        mint,
+        pool_authority,
+        pool_key,
+        pool_authority_bump,
+        amount,
+        fee_bps,
+    )?;
+    let net = amount.checked_sub(fee).ok_or(ErrorCode::ArithmeticOverflow)?;
+    transfer_from_vault(token_program, pool_vault, payee, mint, pool_authority, pool_key, pool_authority_bump, net)?;
+    Ok(fee)
+}
+
+/// Call `deposit` or `withdraw` on the strategy program for the strategy escrow.
+/// Strategies take the escrow, mint, strategy authority (signer) and token program,
+/// followed by their own accounts passed as remaining accounts.
+#[allow(clippy::too_many_arguments)]
+fn strategy_cpi<'info>(
+    instruction: &str,
+    strategy_program: &UncheckedAccount<'info>,
+    strategy_escrow: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    strategy_authority: &UncheckedAccount<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    strategy_accounts: &[AccountInfo<'info>],
+    pool_key: Pubkey,
+    strategy_authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    // Anchor instruction data: sighash of "global:<name>" followed by the amount
+    let mut data = hash(format!("global:{}", instruction).as_bytes()).to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    
+    let mut accounts = vec![
+        AccountMeta::new(strategy_escrow.key(), false),
+        AccountMeta::new_readonly(mint.key(), false),
+        AccountMeta::new_readonly(strategy_authority.key(), true),
+        AccountMeta::new_readonly(token_program.key(), false),
+    ];
+    accounts.extend(strategy_accounts.iter().map(|account| AccountMeta {
+        pubkey: account.key(),
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+    let ix = Instruction {
+        program_id: strategy_program.key(),
+        accounts,
+        data,
+    };
+    
+    let mut account_infos = vec![
+        strategy_escrow.to_account_info(),
+        mint.to_account_info(),
+        strategy_authority.to_account_info(),
+        token_program.to_account_info(),
+    ];
+    account_infos.extend_from_slice(strategy_accounts);
+    account_infos.push(strategy_program.to_account_info());
+    
+    let seeds = &[
+        b"strategy_authority",
+        pool_key.as_ref(),
+        &[strategy_authority_bump],
+    ];
+    invoke_signed(&ix, &account_infos, &[&seeds[..]])?;
+    Ok(())
+}
+
+/// Reject accepted mints whose Token-2022 extensions the pool's accounting cannot support
+fn validate_accepted_mint(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+    
+    // Payouts and withdrawals move exact amounts, so transfer fees are not supported, and
+    // neither are hooks, confidential balances, permanent delegates or non-transferable mints
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    for extension in state.get_extension_types()? {
+        match extension {
+            ExtensionType::TransferFeeConfig
+            | ExtensionType::TransferHook
+            | ExtensionType::ConfidentialTransferMint
+            | ExtensionType::ConfidentialTransferFeeConfig
+            | ExtensionType::PermanentDelegate
+            | ExtensionType::NonTransferable => return err!(ErrorCode::UnsupportedMintExtension),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Mint pool shares, signed by the pool authority PDA
+fn share_mint_cpi<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    share_mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    pool_authority: &UncheckedAccount<'info>,
+    pool_key: Pubkey,
+    pool_authority_bump: u8,
+    shares: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"pool_authority",
+        pool_key.as_ref(),
+        &[pool_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = MintTo {
+        mint: share_mint.to_account_info(),
+        to: to.to_account_info(),
+        authority: pool_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token_interface::mint_to(cpi_ctx, shares)
+}
+
+// ============ CONTEXTS ============
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Pool::LEN,
+        seeds = [b"pool"],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureClaimType<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateShareMint<'info> {
+    /// CHECK: Created and initialized as a Token-2022 mint by this instruction
+    #[account(
+        mut,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: UncheckedAccount<'info>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    /// CHECK: PDA authority for pool vault and share mint
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateCoverageMint<'info> {
+    /// CHECK: Created and initialized as a Token-2022 mint by this instruction
+    #[account(
+        mut,
+        seeds = [b"coverage_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub coverage_mint: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    /// CHECK: PDA authority for pool vault and coverage mint
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
 pub struct JoinPool<'info> {
     #[account(
         init,
-        payer = user,
-        space = Member::LEN,
-        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
-        bump
+        payer = user,
+        space = Member::LEN,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Fee treasury, required while the deposit fee is set
+    #[account(
+        mut,
+        seeds = [b"treasury", pool.key().as_ref(), pool.accepted_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = share_token_program
+    )]
+    pub member_share_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the share mint
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct DepositAsset<'info> {
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(constraint = pool.asset(&asset_mint.key()).is_some() @ ErrorCode::UnsupportedAsset)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub asset_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Fee treasury for the asset, required while the deposit fee is set
+    #[account(
+        mut,
+        seeds = [b"treasury", pool.key().as_ref(), asset_mint.key().as_ref()],
+        bump
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: PDA authority for pool vaults
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = share_token_program
+    )]
+    pub member_share_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the share mint
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct PayPremium<'info> {
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Fee treasury, required while the premium fee is set
+    #[account(
+        mut,
+        seeds = [b"treasury", pool.key().as_ref(), pool.accepted_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Backstop vault, required while the backstop premium share is set
+    #[account(
+        mut,
+        seeds = [b"backstop", pool.key().as_ref()],
+        bump
+    )]
+    pub backstop: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// Coverage credit mint, required once the pool has one
+    #[account(
+        mut,
+        address = pool.coverage_mint @ ErrorCode::CoverageAccountsRequired
+    )]
+    pub coverage_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = pool.coverage_mint,
+        token::authority = user
+    )]
+    pub member_coverage_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireCoverageCredits<'info> {
+    #[account(
+        seeds = [b"member", pool.key().as_ref(), member.member.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        address = pool.coverage_mint @ ErrorCode::CoverageAccountsRequired
+    )]
+    pub coverage_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = pool.coverage_mint,
+        token::authority = member.member
+    )]
+    pub member_coverage_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: PDA authority for pool vault, permanent delegate of the coverage mint
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = share_token_program
+    )]
+    pub member_share_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the share mint
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAsset<'info> {
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(constraint = pool.asset(&asset_mint.key()).is_some() @ ErrorCode::UnsupportedAsset)]
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub member_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub asset_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Primary vault, counted towards the reserve
+    #[account(
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = base_token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: PDA authority for pool vaults
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = share_token_program
+    )]
+    pub member_share_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// Token program of the asset mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the primary accepted mint
+    pub base_token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the share mint
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterUnderwriter<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = Underwriter::LEN,
+        seeds = [b"underwriter", pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub underwriter: Account<'info, Underwriter>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Underwrite<'info> {
+    #[account(
+        mut,
+        seeds = [b"underwriter", pool.key().as_ref(), user.key().as_ref()],
+        bump = underwriter.bump
+    )]
+    pub underwriter: Account<'info, Underwriter>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCapital<'info> {
+    #[account(
+        mut,
+        seeds = [b"underwriter", pool.key().as_ref(), user.key().as_ref()],
+        bump = underwriter.bump
+    )]
+    pub underwriter: Account<'info, Underwriter>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(category: DeviceCategory, serial_hash: [u8; 32])]
+pub struct RegisterDevice<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = Policy::LEN,
+        seeds = [b"policy", pool.key().as_ref(), user.key().as_ref(), serial_hash.as_ref()],
+        bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RenewPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"policy", pool.key().as_ref(), user.key().as_ref(), policy.serial_hash.as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitClaim<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = Claim::LEN,
+        seeds = [b"claim", pool.key().as_ref(), pool.claim_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    /// Vault of the claim's payout mint
+    #[account(
+        associated_token::mint = pool_vault.mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// Device policy the claim is made against
+    #[account(
+        mut,
+        seeds = [b"policy", pool.key().as_ref(), user.key().as_ref(), policy.serial_hash.as_ref()],
+        bump = policy.bump,
+        constraint = policy.owner == user.key() @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Registered vendor to pay instead of the claimant
+    #[account(
+        seeds = [b"vendor", pool.key().as_ref(), vendor.wallet.as_ref()],
+        bump = vendor.bump
+    )]
+    pub vendor: Option<Account<'info, Vendor>>,
+    
+    /// Mint to be paid out in, the accepted mint if omitted
+    pub payout_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = share_token_program
+    )]
+    pub member_share_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Token program of the payout mint
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program of the share mint
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AddEvidence<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = Evidence::LEN,
+        seeds = [b"evidence", claim.key().as_ref(), &[claim.evidence_count]],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+    
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump,
+        constraint = claim.claimant == user.key() @ ErrorCode::NotClaimant
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VoteClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    #[account(
+        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = share_token_program
+    )]
+    pub member_share_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub user: Signer<'info>,
+    
+    /// Token program of the share mint
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against, released from the pending claim amount
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, released from the pending claim amount
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OracleDecideClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against, released from the pending claim amount
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, released from the pending claim amount
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = oracle @ ErrorCode::InvalidOracle
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureAssessors<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitAssessment<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against, released from the pending claim amount
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, released from the pending claim amount
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    /// CHECK: Instructions sysvar, used to inspect the Ed25519 signature instruction
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateCommittee<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Committee::LEN,
+        seeds = [b"committee", pool.key().as_ref()],
+        bump
+    )]
+    pub committee: Account<'info, Committee>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureCommittee<'info> {
+    /// Committee the setting applies to, which must exist
+    #[account(
+        seeds = [b"committee", pool.key().as_ref()],
+        bump = committee.bump
+    )]
+    pub committee: Account<'info, Committee>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RotateCommittee<'info> {
+    #[account(
+        mut,
+        seeds = [b"committee", pool.key().as_ref()],
+        bump = committee.bump
+    )]
+    pub committee: Account<'info, Committee>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[derive(Accounts)]
+pub struct AttestClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against, released from the pending claim amount
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, released from the pending claim amount
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        seeds = [b"committee", pool.key().as_ref()],
+        bump = committee.bump
+    )]
+    pub committee: Account<'info, Committee>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub attester: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePayoutSchedule<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PayoutSchedule::LEN,
+        seeds = [b"payout_schedule", claim.key().as_ref()],
+        bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+    
+    #[account(
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecutePayout<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, credited with the payout
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = claim.payout_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token account of the claimant, or of the claim's vendor
+    #[account(
+        mut,
+        token::mint = claim.payout_mint
+    )]
+    pub payee_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = claim.payout_mint)]
+    pub payout_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Fee treasury for the payout mint, required while the payout fee is set
+    #[account(
+        mut,
+        seeds = [b"treasury", pool.key().as_ref(), claim.payout_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// Vendor named on the claim, if any
+    pub vendor: Option<Account<'info, Vendor>>,
+    
+    /// Payout schedule, required when the claim is paid in installments
+    #[account(
+        mut,
+        seeds = [b"payout_schedule", claim.key().as_ref()],
+        bump = payout_schedule.bump
+    )]
+    pub payout_schedule: Option<Account<'info, PayoutSchedule>>,
+    
+    /// Coverage credit mint, required while premiums are enabled once the pool has one
+    #[account(
+        mut,
+        address = pool.coverage_mint @ ErrorCode::CoverageAccountsRequired
+    )]
+    pub coverage_mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = pool.coverage_mint,
+        token::authority = claim.claimant
+    )]
+    pub claimant_coverage_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct VetoClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against, released from the pending claim amount
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, released from the pending claim amount
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePayoutDelay<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleShortfall<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, credited with the payout
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        associated_token::mint = claim.payout_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token account of the claimant, or of the claim's vendor
+    #[account(
+        mut,
+        token::mint = claim.payout_mint
+    )]
+    pub payee_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = claim.payout_mint)]
+    pub payout_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Fee treasury for the payout mint, required while the payout fee is set
+    #[account(
+        mut,
+        seeds = [b"treasury", pool.key().as_ref(), claim.payout_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// Vendor named on the claim, if any
+    pub vendor: Option<Account<'info, Vendor>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CreateBackstop<'info> {
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"backstop", pool.key().as_ref()],
+        bump,
+        token::mint = accepted_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program
+    )]
+    pub backstop: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    /// CHECK: PDA authority for pool vaults
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureBackstop<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FundBackstop<'info> {
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        seeds = [b"backstop", pool.key().as_ref()],
+        bump
+    )]
+    pub backstop: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = pool.accepted_mint,
+        token::authority = funder
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    pub funder: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct DrawBackstop<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    /// Device policy the claim was made against
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, credited with the payout
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    #[account(
+        mut,
+        seeds = [b"backstop", pool.key().as_ref()],
+        bump
+    )]
+    pub backstop: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token account of the claimant, or of the claim's vendor
+    #[account(
+        mut,
+        token::mint = pool.accepted_mint
+    )]
+    pub payee_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA authority for the backstop vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// Vendor named on the claim, if any
+    pub vendor: Option<Account<'info, Vendor>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTreasury<'info> {
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"treasury", pool.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool_authority,
+        token::token_program = token_program
     )]
-    pub member: Account<'info, Member>,
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(constraint = pool.is_accepted(&mint.key()) @ ErrorCode::UnsupportedAsset)]
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
-        mut,
         seeds = [b"pool"],
-        bump = pool.bump
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
     pub pool: Account<'info, Pool>,
     
+    /// CHECK: PDA authority for pool vaults and treasuries
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub admin: Signer<'info>,
     
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Deposit<'info> {
+pub struct ConfigureFees<'info> {
     #[account(
         mut,
-        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
-        bump = member.bump
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub member: Account<'info, Member>,
+    pub pool: Account<'info, Pool>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
     #[account(
         mut,
-        seeds = [b"pool"],
-        bump = pool.bump
+        seeds = [b"treasury", pool.key().as_ref(), mint.key().as_ref()],
+        bump
     )]
-    pub pool: Account<'info, Pool>,
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         mut,
-        associated_token::mint = pool.accepted_mint,
-        associated_token::authority = user
+        token::mint = mint
     )]
-    pub member_token_account: Account<'info, TokenAccount>,
+    pub destination: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
-        mut,
-        associated_token::mint = pool.accepted_mint,
-        associated_token::authority = pool_authority
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub pool: Account<'info, Pool>,
     
-    /// CHECK: PDA authority for pool vault
+    /// CHECK: PDA authority for treasuries
     #[account(
         seeds = [b"pool_authority", pool.key().as_ref()],
         bump
     )]
     pub pool_authority: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub user: Signer<'info>,
+    pub admin: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct Withdraw<'info> {
+pub struct ConfigureStrategy<'info> {
     #[account(
         mut,
-        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
-        bump = member.bump
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub member: Account<'info, Member>,
+    pub pool: Account<'info, Pool>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeployToStrategy<'info> {
     #[account(
         mut,
         seeds = [b"pool"],
-        bump = pool.bump
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
     pub pool: Account<'info, Pool>,
     
     #[account(
         mut,
         associated_token::mint = pool.accepted_mint,
-        associated_token::authority = user
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA authority for pool vault
+    #[account(
+        seeds = [b"pool_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns the strategy escrow and the strategy position, signing strategy calls
+    #[account(
+        seeds = [b"strategy_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    
+    /// Holds funds on their way to and from the strategy
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = strategy_authority,
+        associated_token::token_program = token_program
+    )]
+    pub strategy_escrow: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Lending program configured by the admin
+    #[account(
+        executable,
+        address = pool.strategy_program @ ErrorCode::StrategyNotConfigured
+    )]
+    pub strategy_program: UncheckedAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RecallFromStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub member_token_account: Account<'info, TokenAccount>,
+    pub pool: Account<'info, Pool>,
     
     #[account(
         mut,
         associated_token::mint = pool.accepted_mint,
-        associated_token::authority = pool_authority
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = pool.accepted_mint)]
+    pub accepted_mint: InterfaceAccount<'info, Mint>,
     
     /// CHECK: PDA authority for pool vault
     #[account(
@@ -416,81 +4230,234 @@ pub struct Withdraw<'info> {
     )]
     pub pool_authority: UncheckedAccount<'info>,
     
-    #[account(mut)]
-    pub user: Signer<'info>,
+    /// CHECK: PDA that owns the strategy escrow and the strategy position, signing strategy calls
+    #[account(
+        seeds = [b"strategy_authority", pool.key().as_ref()],
+        bump
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    
+    /// Holds funds on their way to and from the strategy
+    #[account(
+        mut,
+        associated_token::mint = pool.accepted_mint,
+        associated_token::authority = strategy_authority,
+        associated_token::token_program = token_program
+    )]
+    pub strategy_escrow: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Lending program configured by the admin
+    #[account(
+        executable,
+        address = pool.strategy_program @ ErrorCode::StrategyNotConfigured
+    )]
+    pub strategy_program: UncheckedAccount<'info>,
+    
+    pub admin: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitClaim<'info> {
+pub struct RecognizeStrategyLoss<'info> {
     #[account(
-        init,
-        payer = user,
-        space = Claim::LEN,
-        seeds = [b"claim", pool.key().as_ref(), pool.claim_count.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub claim: Account<'info, Claim>,
+    pub pool: Account<'info, Pool>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureAsset<'info> {
     #[account(
-        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
-        bump = member.bump
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub member: Account<'info, Member>,
+    pub pool: Account<'info, Pool>,
+    
+    pub asset_mint: InterfaceAccount<'info, Mint>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureReserve<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureDepositLimits<'info> {
     #[account(
         mut,
         seeds = [b"pool"],
-        bump = pool.bump
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
     pub pool: Account<'info, Pool>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureClaimVesting<'info> {
     #[account(
-        associated_token::mint = pool.accepted_mint,
-        associated_token::authority = pool_authority
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub pool: Account<'info, Pool>,
     
-    /// CHECK: PDA authority for pool vault
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureClaimCaps<'info> {
     #[account(
-        seeds = [b"pool_authority", pool.key().as_ref()],
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePolicyTerm<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureMaxDeclaredValue<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePremiums<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterVendor<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Vendor::LEN,
+        seeds = [b"vendor", pool.key().as_ref(), vendor_wallet.key().as_ref()],
         bump
     )]
-    pub pool_authority: UncheckedAccount<'info>,
+    pub vendor: Account<'info, Vendor>,
+    
+    #[account(
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+    
+    /// CHECK: Vendor wallet, only its address is recorded
+    pub vendor_wallet: UncheckedAccount<'info>,
     
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub admin: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct VoteClaim<'info> {
+pub struct SetVendorActive<'info> {
     #[account(
         mut,
-        seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
-        bump = claim.bump
+        seeds = [b"vendor", pool.key().as_ref(), vendor.wallet.as_ref()],
+        bump = vendor.bump
     )]
-    pub claim: Account<'info, Claim>,
+    pub vendor: Account<'info, Vendor>,
     
     #[account(
-        seeds = [b"member", pool.key().as_ref(), user.key().as_ref()],
-        bump = member.bump
+        seeds = [b"pool"],
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
-    pub member: Account<'info, Member>,
+    pub pool: Account<'info, Pool>,
     
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureInstallments<'info> {
     #[account(
+        mut,
         seeds = [b"pool"],
-        bump = pool.bump
+        bump = pool.bump,
+        has_one = admin @ ErrorCode::Unauthorized
     )]
     pub pool: Account<'info, Pool>,
     
-    pub user: Signer<'info>,
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct FinalizeClaim<'info> {
+pub struct ReleasePayout<'info> {
+    #[account(
+        mut,
+        seeds = [b"payout_schedule", claim.key().as_ref()],
+        bump = payout_schedule.bump
+    )]
+    pub payout_schedule: Account<'info, PayoutSchedule>,
+    
     #[account(
         mut,
         seeds = [b"claim", pool.key().as_ref(), claim.claim_id.to_le_bytes().as_ref()],
@@ -498,6 +4465,21 @@ pub struct FinalizeClaim<'info> {
     )]
     pub claim: Account<'info, Claim>,
     
+    /// Device policy the claim was made against
+    #[account(
+        mut,
+        address = claim.policy @ ErrorCode::InvalidPolicy
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Claimant's membership, credited with the payout
+    #[account(
+        mut,
+        seeds = [b"member", pool.key().as_ref(), claim.claimant.as_ref()],
+        bump = member.bump
+    )]
+    pub member: Account<'info, Member>,
+    
     #[account(
         mut,
         seeds = [b"pool"],
@@ -507,17 +4489,29 @@ pub struct FinalizeClaim<'info> {
     
     #[account(
         mut,
-        associated_token::mint = pool.accepted_mint,
-        associated_token::authority = pool_authority
+        associated_token::mint = claim.payout_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
     
+    /// Token account of the claimant, or of the claim's vendor
     #[account(
         mut,
-        associated_token::mint = pool.accepted_mint,
-        associated_token::authority = claimant
+        token::mint = claim.payout_mint
     )]
-    pub claimant_token_account: Account<'info, TokenAccount>,
+    pub payee_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = claim.payout_mint)]
+    pub payout_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Fee treasury for the payout mint, required while the payout fee is set
+    #[account(
+        mut,
+        seeds = [b"treasury", pool.key().as_ref(), claim.payout_mint.as_ref()],
+        bump
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
     
     /// CHECK: PDA authority for pool vault
     #[account(
@@ -526,11 +4520,8 @@ pub struct FinalizeClaim<'info> {
     )]
     pub pool_authority: UncheckedAccount<'info>,
     
-    /// CHECK: Claimant address from claim account
-    #[account(
-        constraint = claimant.key() == claim.claimant @ ErrorCode::InvalidClaimant
-    )]
-    pub claimant: UncheckedAccount<'info>,
+    /// Vendor named on the claim, if any
+    pub vendor: Option<Account<'info, Vendor>>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
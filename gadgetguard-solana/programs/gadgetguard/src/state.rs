@@ -5,42 +5,331 @@ use anchor_lang::prelude::*;
 pub struct Pool {
     /// Admin who can initialize and manage the pool
     pub admin: Pubkey,
-    /// SPL token mint accepted for deposits (e.g., USDC)
+    /// SPL token mint accepted for deposits (e.g., USDC), the pool's unit of account
     pub accepted_mint: Pubkey,
-    /// Total amount deposited across all members
+    /// Decimals of the accepted mint
+    pub base_decimals: u8,
+    /// Additional accepted mints, each with its own vault (max 4)
+    pub assets: Vec<AcceptedAsset>,
+    /// Value backing the pool share mint: deposits and income, less withdrawals and uncovered losses
     pub total_deposits: u64,
     /// Total amount paid out in approved claims
     pub total_paid_out: u64,
+    /// Amount owed to approved claims that the vault could not cover
+    pub total_shortfall: u64,
     /// Number of claims submitted
     pub claim_count: u64,
     /// Number of active members
     pub member_count: u64,
     /// Maximum claim percentage (basis points, e.g., 5000 = 50%)
     pub max_claim_pct: u16,
-    /// Voting window duration in seconds
+    /// Default voting window duration in seconds
     pub vote_window_secs: i64,
-    /// Minimum number of votes required for quorum
+    /// Default minimum number of votes required for quorum
     pub quorum: u8,
-    /// Approval ratio required (basis points, e.g., 6000 = 60%)
+    /// Default approval ratio required (basis points, e.g., 6000 = 60%)
     pub approval_ratio: u16,
+    /// Oracle authority that can decide claims (default pubkey = none)
+    pub oracle: Pubkey,
+    /// How pending claims are decided
+    pub decision_mode: DecisionMode,
+    /// Keys whose Ed25519-signed assessments act as oracle decisions (max 5)
+    pub assessors: Vec<Pubkey>,
+    /// Whether the assessor committee decides claims in the oracle decision modes
+    pub committee_active: bool,
+    /// Per-claim-type rules, indexed by `ClaimType`
+    pub claim_type_rules: [ClaimTypeRules; ClaimType::COUNT],
+    /// Seconds between claim approval and payout, during which the admin can veto
+    pub payout_delay_secs: i64,
+    /// Coverage term of newly registered device policies in seconds
+    pub policy_term_secs: i64,
+    /// Largest declared value a device can be insured for (0 = uncapped, not allowed with premiums)
+    pub max_declared_value: u64,
+    /// Monthly premium per member (0 = premiums disabled)
+    pub premium_per_month: u64,
+    /// Consecutive paid months required before a member can claim
+    pub min_months_for_claim: u8,
+    /// Total non-refundable premiums collected
+    pub total_premiums: u64,
+    /// Non-transferable Token-2022 mint of coverage credits (default pubkey = none)
+    pub coverage_mint: Pubkey,
+    /// Capital contributed by underwriters, net of withdrawals and losses
+    pub underwriter_capital: u64,
+    /// Premium income earned by underwriters, net of withdrawals and losses
+    pub premium_income: u64,
+    /// Total underwriter shares outstanding
+    pub underwriter_shares: u64,
+    /// Requested or approved amounts of open claims not yet paid
+    pub outstanding_exposure: u64,
+    /// Approved claims above this amount are paid in installments (0 = disabled)
+    pub installment_threshold: u64,
+    /// Number of tranches in an installment schedule
+    pub installment_count: u8,
+    /// Seconds between installment tranches
+    pub installment_interval_secs: i64,
+    /// Fee on deposits, routed to the treasury (basis points)
+    pub deposit_fee_bps: u16,
+    /// Fee on premiums, routed to the treasury (basis points)
+    pub premium_fee_bps: u16,
+    /// Fee withheld from payouts, routed to the treasury (basis points)
+    pub payout_fee_bps: u16,
+    /// Total fees collected, in accepted mint base units
+    pub fees_collected: u64,
+    /// Lending program idle vault funds can be deployed to (default pubkey = none)
+    pub strategy_program: Pubkey,
+    /// Share of primary mint funds that must stay liquid in the vault (basis points)
+    pub min_liquid_reserve_bps: u16,
+    /// Primary mint funds currently deployed to the strategy
+    pub strategy_deployed: u64,
+    /// Share of premiums paid to the backstop vault (basis points)
+    pub backstop_premium_bps: u16,
+    /// Total drawn from the backstop vault to cover claim shortfalls
+    pub total_backstop_draws: u64,
+    /// Funds withdrawals must leave in the pool, relative to the claim limits all deposits back (basis points)
+    pub min_reserve_bps: u16,
+    /// Smallest accepted deposit
+    pub min_deposit: u64,
+    /// Maximum value of one member's shares after a deposit (0 = unlimited)
+    pub max_deposit_per_member: u64,
+    /// Maximum `total_deposits` (0 = unlimited)
+    pub pool_capacity: u64,
+    /// Seconds over which deposits vest into claimable coverage (0 = immediately)
+    pub claim_vesting_secs: i64,
+    /// Length of the period over which payouts count against a member's claim limit
+    pub claim_period_secs: i64,
+    /// Maximum total payouts per member over their membership (0 = unlimited)
+    pub lifetime_payout_cap: u64,
+    /// Merkle root of wallets allowed to join (all zeros = open membership)
+    pub allowlist_root: [u8; 32],
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
 impl Pool {
+    /// Maximum number of registered assessor keys
+    pub const MAX_ASSESSORS: usize = 5;
+
+    /// Maximum number of additional accepted mints
+    pub const MAX_ASSETS: usize = 4;
+
+    /// Maximum fee on deposits, premiums or payouts (10%)
+    pub const MAX_FEE_BPS: u16 = 1000;
+
+    /// Maximum share of premiums paid to the backstop (50%)
+    pub const MAX_BACKSTOP_PREMIUM_BPS: u16 = 5000;
+
+    /// Whether deposits and payouts can be made in `mint`
+    pub fn is_accepted(&self, mint: &Pubkey) -> bool {
+        *mint == self.accepted_mint || self.asset(mint).is_some()
+    }
+
+    /// Additional accepted asset for `mint`, if any
+    pub fn asset(&self, mint: &Pubkey) -> Option<&AcceptedAsset> {
+        self.assets.iter().find(|asset| asset.mint == *mint)
+    }
+
+    /// Value of `amount` of `mint` in accepted mint base units
+    pub fn to_base_value(&self, mint: &Pubkey, amount: u64) -> Option<u64> {
+        if *mint == self.accepted_mint {
+            return Some(amount);
+        }
+        let asset = self.asset(mint)?;
+        let value = (amount as u128).checked_mul(asset.rate_bps as u128)? / 10000;
+        u64::try_from(rescale(value, asset.decimals, self.base_decimals)?).ok()
+    }
+
+    /// Amount of `mint` worth `value` accepted mint base units, rounded down
+    pub fn from_base_value(&self, mint: &Pubkey, value: u64) -> Option<u64> {
+        if *mint == self.accepted_mint {
+            return Some(value);
+        }
+        let asset = self.asset(mint)?;
+        let amount = rescale(value as u128, self.base_decimals, asset.decimals)?
+            .checked_mul(10000)?
+            .checked_div(asset.rate_bps as u128)?;
+        u64::try_from(amount).ok()
+    }
+
+    /// Value backing underwriter shares: capital plus premium income
+    pub fn underwriter_value(&self) -> u64 {
+        self.underwriter_capital.saturating_add(self.premium_income)
+    }
+
+    /// Take `amount` out of underwriter funds, premium income first, returning what they could not cover
+    pub fn draw_underwriter_funds(&mut self, amount: u64) -> u64 {
+        let from_income = amount.min(self.premium_income);
+        self.premium_income -= from_income;
+        let from_capital = (amount - from_income).min(self.underwriter_capital);
+        self.underwriter_capital -= from_capital;
+        amount - from_income - from_capital
+    }
+
+    /// Claim limit backed by pool shares worth `share_value`
+    pub fn claim_limit_for(&self, share_value: u64) -> u64 {
+        ((share_value as u128) * (self.max_claim_pct as u128) / 10000) as u64
+    }
+
+    /// Funds the pool must hold to meet the minimum reserve ratio, from the claim limits of all deposits
+    pub fn required_reserve(&self) -> u64 {
+        let claim_limits = self.claim_limit_for(self.total_deposits);
+        ((claim_limits as u128) * (self.min_reserve_bps as u128) / 10000) as u64
+    }
+
+    /// Drop `amount` of claim exposure that has been paid or no longer applies
+    pub fn release_exposure(&mut self, amount: u64) {
+        self.outstanding_exposure = self.outstanding_exposure.saturating_sub(amount);
+    }
+
     /// Size of Pool account in bytes
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
         32 + // accepted_mint
+        1 + // base_decimals
+        4 + (AcceptedAsset::LEN * Pool::MAX_ASSETS) + // assets (vec with length prefix)
         8 + // total_deposits
         8 + // total_paid_out
+        8 + // total_shortfall
         8 + // claim_count
         8 + // member_count
         2 + // max_claim_pct
         8 + // vote_window_secs
         1 + // quorum
         2 + // approval_ratio
+        32 + // oracle
+        1 + // decision_mode
+        4 + (32 * Pool::MAX_ASSESSORS) + // assessors (vec with length prefix)
+        1 + // committee_active
+        ClaimTypeRules::LEN * ClaimType::COUNT + // claim_type_rules
+        8 + // payout_delay_secs
+        8 + // policy_term_secs
+        8 + // max_declared_value
+        8 + // premium_per_month
+        1 + // min_months_for_claim
+        8 + // total_premiums
+        32 + // coverage_mint
+        8 + // underwriter_capital
+        8 + // premium_income
+        8 + // underwriter_shares
+        8 + // outstanding_exposure
+        8 + // installment_threshold
+        1 + // installment_count
+        8 + // installment_interval_secs
+        2 + // deposit_fee_bps
+        2 + // premium_fee_bps
+        2 + // payout_fee_bps
+        8 + // fees_collected
+        32 + // strategy_program
+        2 + // min_liquid_reserve_bps
+        8 + // strategy_deployed
+        2 + // backstop_premium_bps
+        8 + // total_backstop_draws
+        2 + // min_reserve_bps
+        8 + // min_deposit
+        8 + // max_deposit_per_member
+        8 + // pool_capacity
+        8 + // claim_vesting_secs
+        8 + // claim_period_secs
+        8 + // lifetime_payout_cap
+        32 + // allowlist_root
         1; // bump
+
+    /// Whether someone can decide claims under the decision mode: an oracle key,
+    /// an assessor or the committee, unless members vote
+    pub fn has_decider(&self) -> bool {
+        self.decision_mode == DecisionMode::VotesOnly
+            || self.oracle != Pubkey::default()
+            || !self.assessors.is_empty()
+            || self.committee_active
+    }
+
+    /// Whether premium policyholders, who hold no shares to vote with, have someone to decide their claims
+    pub fn has_premium_decider(&self) -> bool {
+        self.premium_per_month == 0 || self.decision_mode != DecisionMode::VotesOnly
+    }
+
+    /// Rules that apply to claims of the given type
+    pub fn rules(&self, claim_type: ClaimType) -> &ClaimTypeRules {
+        &self.claim_type_rules[claim_type.index()]
+    }
+
+    /// Whether an approved claim of this amount must be paid in installments
+    pub fn requires_installments(&self, amount: u64) -> bool {
+        self.installment_threshold > 0 && amount > self.installment_threshold
+    }
+}
+
+/// Additional mint a pool accepts, valued against the accepted mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct AcceptedAsset {
+    /// Token mint
+    pub mint: Pubkey,
+    /// Decimals of the mint
+    pub decimals: u8,
+    /// Value of one whole token in whole accepted mint tokens (basis points, 10000 = 1:1)
+    pub rate_bps: u32,
+}
+
+impl AcceptedAsset {
+    /// Serialized size in bytes
+    pub const LEN: usize = 32 + // mint
+        1 + // decimals
+        4; // rate_bps
+}
+
+/// Convert a token amount between decimal precisions, rounding down
+fn rescale(amount: u128, from_decimals: u8, to_decimals: u8) -> Option<u128> {
+    if to_decimals >= from_decimals {
+        amount.checked_mul(10u128.checked_pow((to_decimals - from_decimals) as u32)?)
+    } else {
+        Some(amount / 10u128.checked_pow((from_decimals - to_decimals) as u32)?)
+    }
+}
+
+/// A payout on a member's claims, kept for the rolling claim period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PayoutRecord {
+    /// When the payout was made
+    pub ts: i64,
+    /// Amount paid out
+    pub amount: u64,
+}
+
+impl PayoutRecord {
+    /// Serialized size of PayoutRecord in bytes
+    pub const LEN: usize = 8 + // ts
+        8; // amount
+}
+
+/// Claim rules configured per `ClaimType`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimTypeRules {
+    /// Whether claims of this type can be submitted
+    pub enabled: bool,
+    /// Maximum payout as a share of the member's claim limit (basis points)
+    pub max_payout_bps: u16,
+    /// Voting window duration in seconds
+    pub vote_window_secs: i64,
+    /// Minimum number of votes required for quorum
+    pub quorum: u8,
+    /// Approval ratio required (basis points)
+    pub approval_ratio: u16,
+}
+
+impl ClaimTypeRules {
+    /// Serialized size of ClaimTypeRules in bytes
+    pub const LEN: usize = 1 + // enabled
+        2 + // max_payout_bps
+        8 + // vote_window_secs
+        1 + // quorum
+        2; // approval_ratio
+
+    /// Check that the rules describe a usable governance configuration
+    pub fn is_valid(&self) -> bool {
+        self.max_payout_bps <= 10000
+            && self.vote_window_secs > 0
+            && self.quorum > 0
+            && self.approval_ratio <= 10000
+    }
 }
 
 /// Member account - tracks individual member participation
@@ -50,19 +339,44 @@ pub struct Member {
     pub pool: Pubkey,
     /// Member's wallet address
     pub member: Pubkey,
-    /// Total amount deposited by this member
+    /// Net amount deposited by this member, for reference
     pub deposited_amount: u64,
-    /// Maximum claim amount this member can request
+    /// Claim limit as of the member's last deposit or withdrawal (claims use the live, vested share value)
     pub claim_limit: u64,
     /// Timestamp of last claim submission
     pub last_claim_ts: i64,
     /// Whether member is active
     pub active: bool,
+    /// Timestamp until which premiums have been paid
+    pub coverage_until: i64,
+    /// Consecutive months paid without a lapse in coverage
+    pub months_paid_streak: u32,
+    /// Total premiums paid by this member
+    pub premiums_paid: u64,
+    /// Deposited value still unvested at `vesting_start_ts`, vesting linearly until `vesting_end_ts`
+    pub vesting_amount: u64,
+    /// When `vesting_amount` was last updated
+    pub vesting_start_ts: i64,
+    /// When all deposited value is vested
+    pub vesting_end_ts: i64,
+    /// Total paid out on this member's claims
+    pub total_claimed: u64,
+    /// Number of this member's claims that received a payout
+    pub claims_paid: u32,
+    /// Recent payouts on this member's claims, counted against the claim limit over a rolling period
+    pub recent_payouts: [PayoutRecord; Member::MAX_RECENT_PAYOUTS],
+    /// Requested on this member's claims still awaiting a decision or payout
+    pub pending_claims: u64,
+    /// Slot of the member's latest deposit, so votes only count shares held before a claim
+    pub last_deposit_slot: u64,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
 impl Member {
+    /// Maximum number of payout records kept for the rolling claim period
+    pub const MAX_RECENT_PAYOUTS: usize = 8;
+
     /// Size of Member account in bytes
     pub const LEN: usize = 8 + // discriminator
         32 + // pool
@@ -71,9 +385,195 @@ impl Member {
         8 + // claim_limit
         8 + // last_claim_ts
         1 + // active
+        8 + // coverage_until
+        4 + // months_paid_streak
+        8 + // premiums_paid
+        8 + // vesting_amount
+        8 + // vesting_start_ts
+        8 + // vesting_end_ts
+        8 + // total_claimed
+        4 + // claims_paid
+        PayoutRecord::LEN * Member::MAX_RECENT_PAYOUTS + // recent_payouts
+        8 + // pending_claims
+        8 + // last_deposit_slot
+        1; // bump
+
+    /// Deposited value not yet vested at `now`
+    pub fn unvested_value(&self, now: i64) -> u64 {
+        if now >= self.vesting_end_ts {
+            return 0;
+        }
+        let remaining = (self.vesting_end_ts - now) as u128;
+        let period = (self.vesting_end_ts - self.vesting_start_ts).max(1) as u128;
+        ((self.vesting_amount as u128) * remaining.min(period) / period) as u64
+    }
+
+    /// Vest `value` over `vesting_secs` from `now`; the end moves to the value-weighted
+    /// average of both lots' end times, so earlier deposits keep their progress
+    pub fn add_vesting(&mut self, value: u64, now: i64, vesting_secs: i64) {
+        if value == 0 || vesting_secs <= 0 {
+            return;
+        }
+        let unvested = self.unvested_value(now);
+        let remaining = self.vesting_end_ts.saturating_sub(now).max(0);
+        let total = (unvested as u128) + (value as u128);
+        let weighted = ((unvested as u128) * (remaining as u128) + (value as u128) * (vesting_secs as u128))
+            .div_ceil(total) as i64;
+        
+        self.vesting_amount = total.min(u64::MAX as u128) as u64;
+        self.vesting_start_ts = now;
+        self.vesting_end_ts = now.saturating_add(weighted);
+    }
+
+    /// Cap the unvested value at `max`, e.g. after a withdrawal took vested value out first
+    pub fn clamp_vesting(&mut self, max: u64, now: i64) {
+        let unvested = self.unvested_value(now);
+        if unvested > max {
+            self.vesting_amount = max;
+            self.vesting_start_ts = now;
+        }
+    }
+
+    /// Payouts counted against the claim limit in the `period_secs` before `now`
+    pub fn claimed_in_period(&self, now: i64, period_secs: i64) -> u64 {
+        let since = now.saturating_sub(period_secs);
+        self.recent_payouts
+            .iter()
+            .filter(|record| record.ts > since)
+            .fold(0u64, |total, record| total.saturating_add(record.amount))
+    }
+
+    /// Record a payout at `now`; once every record is still in the period, the newest one
+    /// absorbs it and moves to `now`, so the amount is never counted for less than a full period
+    pub fn record_claimed(&mut self, amount: u64, first_payment: bool, now: i64, period_secs: i64) {
+        let since = now.saturating_sub(period_secs);
+        let slot = match self.recent_payouts.iter().position(|record| record.ts <= since) {
+            Some(index) => {
+                self.recent_payouts[index] = PayoutRecord::default();
+                index
+            }
+            None => self
+                .recent_payouts
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, record)| record.ts)
+                .map(|(index, _)| index)
+                .unwrap_or(0),
+        };
+        let record = &mut self.recent_payouts[slot];
+        record.ts = now;
+        record.amount = record.amount.saturating_add(amount);
+        
+        self.pending_claims = self.pending_claims.saturating_sub(amount);
+        self.total_claimed = self.total_claimed.saturating_add(amount);
+        if first_payment {
+            self.claims_paid = self.claims_paid.saturating_add(1);
+        }
+    }
+
+    /// Release the part of a claim's request that will no longer be paid
+    pub fn release_pending(&mut self, amount: u64) {
+        self.pending_claims = self.pending_claims.saturating_sub(amount);
+    }
+
+    /// Whether paid premiums cover the member at `now`
+    pub fn has_premium_coverage(&self, now: i64) -> bool {
+        now < self.coverage_until
+    }
+}
+
+/// Underwriter account - a capital provider that backs claims and earns premiums
+#[account]
+pub struct Underwriter {
+    /// Pool this underwriter belongs to
+    pub pool: Pubkey,
+    /// Underwriter's wallet address
+    pub owner: Pubkey,
+    /// Shares of underwriter capital and premium income held
+    pub shares: u64,
+    /// Total amount contributed, for reference
+    pub contributed_amount: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Underwriter {
+    /// Size of Underwriter account in bytes
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // owner
+        8 + // shares
+        8 + // contributed_amount
         1; // bump
 }
 
+/// Device category enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceCategory {
+    Laptop,
+    Phone,
+    Tablet,
+    Camera,
+    Other,
+}
+
+/// Policy account - insures one specific device for a coverage period
+#[account]
+pub struct Policy {
+    /// Pool this policy belongs to
+    pub pool: Pubkey,
+    /// Member who owns the insured device
+    pub owner: Pubkey,
+    /// Kind of device insured
+    pub category: DeviceCategory,
+    /// Hash of the device serial number
+    pub serial_hash: [u8; 32],
+    /// Declared device value, the policy's aggregate payout cap
+    pub declared_value: u64,
+    /// Timestamp when coverage starts
+    pub coverage_start: i64,
+    /// Timestamp when coverage ends
+    pub coverage_end: i64,
+    /// Total paid out on claims against this policy
+    pub total_paid: u64,
+    /// Requested on claims against this policy still awaiting a decision or payout
+    pub pending_amount: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Policy {
+    /// Size of Policy account in bytes
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // owner
+        1 + // category
+        32 + // serial_hash
+        8 + // declared_value
+        8 + // coverage_start
+        8 + // coverage_end
+        8 + // total_paid
+        8 + // pending_amount
+        1; // bump
+
+    /// Whether the policy covers the device at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.coverage_start <= now && now < self.coverage_end
+    }
+
+    /// Coverage left before the aggregate payout cap is reached, net of pending claims
+    pub fn remaining_coverage(&self) -> u64 {
+        self.declared_value
+            .saturating_sub(self.total_paid)
+            .saturating_sub(self.pending_amount)
+    }
+
+    /// Release the part of a claim's request that will no longer be paid
+    pub fn release_pending(&mut self, amount: u64) {
+        self.pending_amount = self.pending_amount.saturating_sub(amount);
+    }
+}
+
 /// Claim type enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum ClaimType {
@@ -82,6 +582,42 @@ pub enum ClaimType {
     Loss,
 }
 
+impl ClaimType {
+    /// Number of claim types
+    pub const COUNT: usize = 3;
+
+    /// Index of this claim type in per-type tables
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// How pending claims are decided
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DecisionMode {
+    /// Member votes only
+    VotesOnly,
+    /// Oracle decides, member voting is disabled
+    OracleOnly,
+    /// Oracle decides unless members reach quorum and vote otherwise
+    OracleWithOverride,
+}
+
+/// Off-chain claim assessment, signed by a registered assessor with Ed25519
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Assessment {
+    /// Pool the claim belongs to
+    pub pool: Pubkey,
+    /// Claim being assessed
+    pub claim_id: u64,
+    /// Whether the claim is approved
+    pub approve: bool,
+    /// Amount approved for payout (ignored when rejected)
+    pub approved_amount: u64,
+    /// Unix timestamp after which the assessment can no longer be submitted
+    pub expiry: i64,
+}
+
 /// Claim status enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum ClaimStatus {
@@ -89,6 +625,8 @@ pub enum ClaimStatus {
     Approved,
     Rejected,
     Paid,
+    /// Partially paid, remainder due in installments or as a shortfall
+    Paying,
 }
 
 /// Claim account - represents a submitted claim with voting data
@@ -100,39 +638,266 @@ pub struct Claim {
     pub claim_id: u64,
     /// Member who submitted the claim
     pub claimant: Pubkey,
+    /// Device policy the claim is made against
+    pub policy: Pubkey,
     /// Type of claim (damage, theft, loss)
     pub claim_type: ClaimType,
     /// Amount requested (in tokens)
     pub requested_amount: u64,
+    /// Amount payable if approved (requested amount unless reduced by an assessor)
+    pub approved_amount: u64,
+    /// Amount paid out so far
+    pub paid_amount: u64,
+    /// Amount still owed after a pro-rata payout from an underfunded vault
+    pub shortfall_amount: u64,
     /// URI to evidence/documentation (max 200 chars)
     pub evidence_uri: String,
+    /// Content hash of the evidence bundle (e.g. SHA-256)
+    pub evidence_hash: [u8; 32],
+    /// Number of `Evidence` items appended after submission
+    pub evidence_count: u8,
+    /// Registered vendor paid instead of the claimant, if any
+    pub vendor: Option<Pubkey>,
+    /// Mint the claim is paid out in
+    pub payout_mint: Pubkey,
     /// Timestamp when claim was created
     pub created_ts: i64,
+    /// Slot the claim was created in, the snapshot for vote weights
+    pub created_slot: u64,
+    /// End of the voting window, fixed from the claim type's rules at submission
+    pub vote_deadline: i64,
+    /// Votes needed for quorum, fixed from the claim type's rules at submission
+    pub quorum: u8,
+    /// Approval ratio required (basis points), fixed from the claim type's rules at submission
+    pub approval_ratio: u16,
+    /// Timestamp when claim was approved
+    pub approved_ts: i64,
     /// Current status of the claim
     pub status: ClaimStatus,
     /// Number of YES votes
     pub yes_votes: u8,
     /// Number of NO votes
     pub no_votes: u8,
+    /// Pool shares held by YES voters
+    pub yes_weight: u64,
+    /// Pool shares held by NO voters
+    pub no_weight: u64,
     /// List of voters (pubkeys) to prevent double voting (max 32 voters for MVP)
     pub voters: Vec<Pubkey>,
+    /// Oracle decision awaiting the vote window (OracleWithOverride mode)
+    pub oracle_decision: Option<bool>,
+    /// Amount the oracle decision approves, applied only if the decision stands
+    pub oracle_amount: u64,
+    /// Committee epoch the attestation bitmaps belong to
+    pub committee_epoch: u32,
+    /// Bitmap of committee member indexes that attested approval
+    pub approve_attestations: u16,
+    /// Bitmap of committee member indexes that attested rejection
+    pub reject_attestations: u16,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
 impl Claim {
+    /// Maximum number of evidence items that can be appended to a claim
+    pub const MAX_EVIDENCE: u8 = 16;
+
     /// Size of Claim account in bytes (with max voters and evidence URI)
     pub const LEN: usize = 8 + // discriminator
         32 + // pool
         8 + // claim_id
         32 + // claimant
+        32 + // policy
         1 + // claim_type
         8 + // requested_amount
+        8 + // approved_amount
+        8 + // paid_amount
+        8 + // shortfall_amount
         4 + 200 + // evidence_uri (string with length prefix, max 200 chars)
+        32 + // evidence_hash
+        1 + // evidence_count
+        1 + 32 + // vendor (option)
+        32 + // payout_mint
         8 + // created_ts
+        8 + // created_slot
+        8 + // vote_deadline
+        1 + // quorum
+        2 + // approval_ratio
+        8 + // approved_ts
         1 + // status
         1 + // yes_votes
         1 + // no_votes
+        8 + // yes_weight
+        8 + // no_weight
         4 + (32 * 32) + // voters (vec with length prefix, max 32 voters)
+        1 + 1 + // oracle_decision (option)
+        8 + // oracle_amount
+        4 + // committee_epoch
+        2 + // approve_attestations
+        2 + // reject_attestations
+        1; // bump
+
+    /// Move a pending claim to Approved or Rejected
+    pub fn decide(&mut self, approved: bool, now: i64) {
+        if approved {
+            self.status = ClaimStatus::Approved;
+            self.approved_ts = now;
+        } else {
+            self.status = ClaimStatus::Rejected;
+        }
+    }
+}
+
+/// Payout schedule account - pays a large approved claim in tranches over time
+#[account]
+pub struct PayoutSchedule {
+    /// Pool this schedule belongs to
+    pub pool: Pubkey,
+    /// Claim being paid out
+    pub claim: Pubkey,
+    /// Total amount to pay across all tranches
+    pub total_amount: u64,
+    /// Amount released so far
+    pub released_amount: u64,
+    /// Number of tranches in the schedule
+    pub tranche_count: u8,
+    /// Number of tranches released so far
+    pub tranches_released: u8,
+    /// Timestamp when the first tranche becomes releasable, set by execute_payout
+    pub start_ts: i64,
+    /// Seconds between tranches
+    pub interval_secs: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PayoutSchedule {
+    /// Size of PayoutSchedule account in bytes
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // claim
+        8 + // total_amount
+        8 + // released_amount
+        1 + // tranche_count
+        1 + // tranches_released
+        8 + // start_ts
+        8 + // interval_secs
+        1; // bump
+
+    /// Number of tranches that have become releasable at `now`
+    pub fn tranches_due(&self, now: i64) -> u8 {
+        if now < self.start_ts {
+            return 0;
+        }
+        let elapsed_intervals = (now - self.start_ts) / self.interval_secs;
+        elapsed_intervals
+            .saturating_add(1)
+            .min(self.tranche_count as i64) as u8
+    }
+
+    /// Cumulative amount payable once `tranches` tranches are released
+    pub fn cumulative_amount(&self, tranches: u8) -> Option<u64> {
+        if tranches >= self.tranche_count {
+            return Some(self.total_amount);
+        }
+        (self.total_amount as u128)
+            .checked_mul(tranches as u128)?
+            .checked_div(self.tranche_count as u128)
+            .map(|amount| amount as u64)
+    }
+}
+
+/// Vendor account - a repair vendor that claims can be paid out to
+#[account]
+pub struct Vendor {
+    /// Pool this vendor is registered with
+    pub pool: Pubkey,
+    /// Vendor's wallet address, owner of the payout token account
+    pub wallet: Pubkey,
+    /// Whether the vendor can currently receive payouts
+    pub active: bool,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Vendor {
+    /// Size of Vendor account in bytes
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // wallet
+        1 + // active
+        1; // bump
+}
+
+/// Committee account - M-of-N assessor committee that can decide claims
+#[account]
+pub struct Committee {
+    /// Pool this committee decides claims for
+    pub pool: Pubkey,
+    /// Committee member keys (max 10)
+    pub members: Vec<Pubkey>,
+    /// Number of matching attestations needed to decide a claim
+    pub threshold: u8,
+    /// Incremented on every rotation, invalidating earlier attestations
+    pub epoch: u32,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Committee {
+    /// Maximum number of committee members
+    pub const MAX_MEMBERS: usize = 10;
+
+    /// Size of Committee account in bytes
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        4 + (32 * Committee::MAX_MEMBERS) + // members (vec with length prefix)
+        1 + // threshold
+        4 + // epoch
+        1; // bump
+
+    /// Check that a member set and threshold describe a usable committee
+    pub fn is_valid_config(members: &[Pubkey], threshold: u8) -> bool {
+        let unique = members
+            .iter()
+            .enumerate()
+            .all(|(i, member)| !members[..i].contains(member));
+        unique
+            && members.len() <= Committee::MAX_MEMBERS
+            && threshold > 0
+            && threshold as usize <= members.len()
+    }
+
+    /// Index of a committee member, used as its attestation bit
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|member| member == key)
+    }
+}
+
+/// Evidence account - additional evidence appended to a pending claim
+#[account]
+pub struct Evidence {
+    /// Claim this evidence belongs to
+    pub claim: Pubkey,
+    /// Position of this item in the claim's evidence list
+    pub index: u8,
+    /// URI to the evidence (max 200 chars)
+    pub uri: String,
+    /// Content hash of the evidence (e.g. SHA-256)
+    pub hash: [u8; 32],
+    /// Timestamp when the evidence was added
+    pub added_ts: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Evidence {
+    /// Size of Evidence account in bytes
+    pub const LEN: usize = 8 + // discriminator
+        32 + // claim
+        1 + // index
+        4 + 200 + // uri (string with length prefix, max 200 chars)
+        32 + // hash
+        8 + // added_ts
         1; // bump
 }
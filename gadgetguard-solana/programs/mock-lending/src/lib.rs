@@ -0,0 +1,220 @@
+//! Minimal lending program used as a GadgetGuard yield strategy in tests.
+//!
+//! Depositors lend tokens into a per-mint reserve and withdraw them on demand.
+//! `deposit` and `withdraw` take the strategy account layout GadgetGuard uses:
+//! owner token account, mint, owner, token program, then program-specific accounts.
+
+// Anchor 0.30 macros check cfgs for features this crate does not declare
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("2DKfF3RxkMBH1wsctfPyRiv5Z38q6caUDHxjYn2h7sYK");
+
+#[program]
+pub mod mock_lending {
+    use super::*;
+
+    /// Create the reserve token account for a mint
+    pub fn initialize_reserve(_ctx: Context<InitializeReserve>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Open a lending position for an owner
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.mint = ctx.accounts.mint.key();
+        position.amount = 0;
+        position.bump = ctx.bumps.position;
+        Ok(())
+    }
+
+    /// Lend tokens into the reserve
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.owner_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.reserve.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        let position = &mut ctx.accounts.position;
+        position.amount = position.amount.checked_add(amount).ok_or(LendingError::InsufficientPosition)?;
+        Ok(())
+    }
+
+    /// Withdraw lent tokens, plus any accrued interest, from the reserve
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.amount = position.amount.checked_sub(amount).ok_or(LendingError::InsufficientPosition)?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[b"lending_authority", mint_key.as_ref(), &[ctx.bumps.lending_authority]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.reserve.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.lending_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)
+    }
+
+    /// Credit interest to a position, funded by the caller
+    pub fn accrue_interest(ctx: Context<AccrueInterest>, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.reserve.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        let position = &mut ctx.accounts.position;
+        position.amount = position.amount.checked_add(amount).ok_or(LendingError::InsufficientPosition)?;
+        Ok(())
+    }
+}
+
+/// Lending position - tokens an owner can withdraw from the reserve
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Position {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+}
+
+#[error_code]
+pub enum LendingError {
+    #[msg("Position balance too low")]
+    InsufficientPosition,
+}
+
+#[derive(Accounts)]
+pub struct InitializeReserve<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"reserve", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = lending_authority,
+        token::token_program = token_program
+    )]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA authority for the reserve
+    #[account(seeds = [b"lending_authority", mint.key().as_ref()], bump)]
+    pub lending_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = Position::LEN,
+        seeds = [b"position", mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, Position>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Any account, usually a program PDA, can own a position
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, token::mint = mint, token::authority = owner)]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, seeds = [b"reserve", mint.key().as_ref()], bump)]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, token::mint = mint)]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, seeds = [b"reserve", mint.key().as_ref()], bump)]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for the reserve
+    #[account(seeds = [b"lending_authority", mint.key().as_ref()], bump)]
+    pub lending_authority: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueInterest<'info> {
+    #[account(mut, token::mint = mint, token::authority = funder)]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [b"position", mint.key().as_ref(), position.owner.as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, seeds = [b"reserve", mint.key().as_ref()], bump)]
+    pub reserve: InterfaceAccount<'info, TokenAccount>,
+}
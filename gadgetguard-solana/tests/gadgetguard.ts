@@ -1,17 +1,30 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Gadgetguard } from "../target/types/gadgetguard";
-import { PublicKey, SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { MockLending } from "../target/types/mock_lending";
+import {
+    PublicKey,
+    SystemProgram,
+    Keypair,
+    LAMPORTS_PER_SOL,
+    Ed25519Program,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import {
     TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
     createMint,
     createAccount,
     mintTo,
     getAccount,
+    getMint,
     getAssociatedTokenAddress,
+    getAssociatedTokenAddressSync,
     createAssociatedTokenAccount,
+    transferChecked,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { createHash } from "crypto";
 
 describe("gadgetguard", () => {
     // Configure the client to use the local cluster
@@ -36,12 +49,46 @@ describe("gadgetguard", () => {
     let member2Pda: PublicKey;
     let member3Pda: PublicKey;
 
+    let member1PolicyPda: PublicKey;
+    let member2PolicyPda: PublicKey;
+    let member3PolicyPda: PublicKey;
+
+    let shareMint: PublicKey;
+    let member1ShareAccount: PublicKey;
+    let member2ShareAccount: PublicKey;
+    let member3ShareAccount: PublicKey;
+
+    // Additional accepted asset, added by the multi-asset test
+    let assetMint: PublicKey;
+    let assetVault: PublicKey;
+    let member2AssetAccount: PublicKey;
+
     let member1TokenAccount: PublicKey;
     let member2TokenAccount: PublicKey;
     let member3TokenAccount: PublicKey;
 
     const INITIAL_MINT_AMOUNT = 1_000_000_000; // 1,000 tokens (assuming 6 decimals)
     const DEPOSIT_AMOUNT = 100_000_000; // 100 tokens
+    const DECLARED_VALUE = 100_000_000; // 100 tokens per insured device
+
+    // SHA-256 hash of a device serial number
+    const serialHash = (serial: string): number[] =>
+        Array.from(createHash("sha256").update(serial).digest());
+
+    const policyPda = (owner: PublicKey, serial: string): PublicKey =>
+        PublicKey.findProgramAddressSync(
+            [Buffer.from("policy"), poolPda.toBuffer(), owner.toBuffer(), Buffer.from(serialHash(serial))],
+            program.programId
+        )[0];
+
+    // SHA-256 content hash of an evidence bundle (the URI stands in for its content here)
+    const evidenceHash = (content: string): number[] =>
+        Array.from(createHash("sha256").update(content).digest());
+
+    // Pool shares live on a Token-2022 mint
+    const getShareMint = () => getMint(provider.connection, shareMint, undefined, TOKEN_2022_PROGRAM_ID);
+    const getShareAccount = (account: PublicKey) =>
+        getAccount(provider.connection, account, undefined, TOKEN_2022_PROGRAM_ID);
 
     before(async () => {
         // Airdrop SOL to members
@@ -78,6 +125,11 @@ describe("gadgetguard", () => {
             program.programId
         );
 
+        [shareMint] = PublicKey.findProgramAddressSync(
+            [Buffer.from("share_mint"), poolPda.toBuffer()],
+            program.programId
+        );
+
         // Create pool vault (ATA)
         poolVault = await createAssociatedTokenAccount(
             provider.connection,
@@ -182,10 +234,55 @@ describe("gadgetguard", () => {
         assert.equal(poolAccount.memberCount.toString(), "0");
     });
 
+    it("Creates the pool share mint", async () => {
+        await program.methods
+            .createShareMint()
+            .accounts({
+                shareMint: shareMint,
+                acceptedMint: mint,
+                pool: poolPda,
+                poolAuthority: poolAuthority,
+                admin: admin.publicKey,
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const shareMintAccount = await getShareMint();
+        assert.equal(shareMintAccount.mintAuthority.toBase58(), poolAuthority.toBase58());
+        assert.equal(shareMintAccount.supply.toString(), "0");
+
+        // Share accounts are Token-2022 associated token accounts; the mint is non-transferable
+        member1ShareAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            shareMint,
+            member1.publicKey,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+        member2ShareAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            shareMint,
+            member2.publicKey,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+        member3ShareAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            shareMint,
+            member3.publicKey,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+    });
+
     it("Members join the pool", async () => {
         // Member 1 joins
         await program.methods
-            .joinPool()
+            .joinPool([])
             .accounts({
                 member: member1Pda,
                 pool: poolPda,
@@ -197,7 +294,7 @@ describe("gadgetguard", () => {
 
         // Member 2 joins
         await program.methods
-            .joinPool()
+            .joinPool([])
             .accounts({
                 member: member2Pda,
                 pool: poolPda,
@@ -209,7 +306,7 @@ describe("gadgetguard", () => {
 
         // Member 3 joins
         await program.methods
-            .joinPool()
+            .joinPool([])
             .accounts({
                 member: member3Pda,
                 pool: poolPda,
@@ -236,9 +333,14 @@ describe("gadgetguard", () => {
                 pool: poolPda,
                 memberTokenAccount: member1TokenAccount,
                 poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: null,
                 poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member1ShareAccount,
                 user: member1.publicKey,
                 tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member1])
             .rpc();
@@ -251,9 +353,14 @@ describe("gadgetguard", () => {
                 pool: poolPda,
                 memberTokenAccount: member2TokenAccount,
                 poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: null,
                 poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
                 user: member2.publicKey,
                 tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member2])
             .rpc();
@@ -266,9 +373,14 @@ describe("gadgetguard", () => {
                 pool: poolPda,
                 memberTokenAccount: member3TokenAccount,
                 poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: null,
                 poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
                 user: member3.publicKey,
                 tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member3])
             .rpc();
@@ -281,6 +393,9 @@ describe("gadgetguard", () => {
 
         const member1Account = await program.account.member.fetch(member1Pda);
         assert.equal(member1Account.depositedAmount.toString(), DEPOSIT_AMOUNT.toString());
+        // First deposits mint shares 1:1
+        const member1Shares = await getShareAccount(member1ShareAccount);
+        assert.equal(member1Shares.amount.toString(), DEPOSIT_AMOUNT.toString());
         // Claim limit should be 50% of deposit (maxClaimPct = 5000 basis points)
         const expectedClaimLimit = DEPOSIT_AMOUNT / 2;
         assert.equal(member1Account.claimLimit.toString(), expectedClaimLimit.toString());
@@ -289,6 +404,140 @@ describe("gadgetguard", () => {
         assert.equal(vaultAccount.amount.toString(), (DEPOSIT_AMOUNT * 3).toString());
     });
 
+    it("Members register insured devices", async () => {
+        const devices: [Keypair, PublicKey, string, object][] = [
+            [member1, member1Pda, "LAPTOP-SN-0001", { laptop: {} }],
+            [member2, member2Pda, "PHONE-SN-0002", { phone: {} }],
+            [member3, member3Pda, "TABLET-SN-0003", { tablet: {} }],
+        ];
+
+        for (const [user, memberPda, serial, category] of devices) {
+            await program.methods
+                .registerDevice(category as any, serialHash(serial), new anchor.BN(DECLARED_VALUE))
+                .accounts({
+                    policy: policyPda(user.publicKey, serial),
+                    member: memberPda,
+                    pool: poolPda,
+                    user: user.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([user])
+                .rpc();
+        }
+
+        member1PolicyPda = policyPda(member1.publicKey, "LAPTOP-SN-0001");
+        member2PolicyPda = policyPda(member2.publicKey, "PHONE-SN-0002");
+        member3PolicyPda = policyPda(member3.publicKey, "TABLET-SN-0003");
+
+        const policyAccount = await program.account.policy.fetch(member1PolicyPda);
+        assert.equal(policyAccount.owner.toBase58(), member1.publicKey.toBase58());
+        assert.deepEqual(policyAccount.category, { laptop: {} });
+        assert.equal(policyAccount.declaredValue.toString(), DECLARED_VALUE.toString());
+        assert.equal(policyAccount.totalPaid.toString(), "0");
+        assert.isTrue(policyAccount.coverageEnd.gt(policyAccount.coverageStart));
+
+        try {
+            // An owner cannot insure the same serial twice
+            await program.methods
+                .registerDevice({ laptop: {} }, serialHash("LAPTOP-SN-0001"), new anchor.BN(DECLARED_VALUE))
+                .accounts({
+                    policy: member1PolicyPda,
+                    member: member1Pda,
+                    pool: poolPda,
+                    user: member1.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([member1])
+                .rpc();
+
+            assert.fail("Should have thrown error for an already insured device");
+        } catch (err) {
+            assert.include(err.toString(), "already in use");
+        }
+
+        // Declared values are capped by the pool, so a policy cannot be inflated
+        await program.methods
+            .configureMaxDeclaredValue(new anchor.BN(DECLARED_VALUE))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        try {
+            await program.methods
+                .registerDevice({ phone: {} }, serialHash("PHONE-SN-9999"), new anchor.BN(DECLARED_VALUE + 1))
+                .accounts({
+                    policy: policyPda(member1.publicKey, "PHONE-SN-9999"),
+                    member: member1Pda,
+                    pool: poolPda,
+                    user: member1.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([member1])
+                .rpc();
+
+            assert.fail("Should have thrown error for a declared value above the cap");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidDeclaredValue");
+        }
+
+        // Policies are keyed by owner, so another member's registration cannot squat a serial
+        const member2LaptopPda = policyPda(member2.publicKey, "LAPTOP-SN-0001");
+        await program.methods
+            .registerDevice({ laptop: {} }, serialHash("LAPTOP-SN-0001"), new anchor.BN(DECLARED_VALUE))
+            .accounts({
+                policy: member2LaptopPda,
+                member: member2Pda,
+                pool: poolPda,
+                user: member2.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member2])
+            .rpc();
+        const member2Laptop = await program.account.policy.fetch(member2LaptopPda);
+        assert.equal(member2Laptop.owner.toBase58(), member2.publicKey.toBase58());
+    });
+
+    it("Owners renew their device policies for another term", async () => {
+        const configureTerm = (termSecs: number) =>
+            program.methods
+                .configurePolicyTerm(new anchor.BN(termSecs))
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+        const renew = () =>
+            program.methods
+                .renewPolicy()
+                .accounts({
+                    policy: member1PolicyPda,
+                    member: member1Pda,
+                    pool: poolPda,
+                    user: member1.publicKey,
+                })
+                .signers([member1])
+                .rpc();
+
+        // A renewal must extend coverage
+        await configureTerm(3600);
+        try {
+            await renew();
+            assert.fail("Should have thrown error for a renewal that shortens coverage");
+        } catch (err) {
+            assert.include(err.toString(), "PolicyStillActive");
+        }
+
+        const before = await program.account.policy.fetch(member1PolicyPda);
+        await configureTerm(2 * 365 * 86400);
+        await renew();
+        const after = await program.account.policy.fetch(member1PolicyPda);
+        assert.isTrue(after.coverageEnd.gt(before.coverageEnd));
+        assert.equal(after.coverageStart.toString(), before.coverageStart.toString());
+
+        await configureTerm(365 * 86400);
+    });
+
     it("Member submits a claim", async () => {
         const claimAmount = 50_000_000; // 50 tokens (within member1's limit)
         const evidenceUri = "https://evidence.example.com/claim1";
@@ -302,7 +551,8 @@ describe("gadgetguard", () => {
             .submitClaim(
                 { damage: {} }, // ClaimType::Damage
                 new anchor.BN(claimAmount),
-                evidenceUri
+                evidenceUri,
+                evidenceHash(evidenceUri)
             )
             .accounts({
                 claim: claimPda,
@@ -310,7 +560,14 @@ describe("gadgetguard", () => {
                 pool: poolPda,
                 poolVault: poolVault,
                 poolAuthority: poolAuthority,
+                policy: member1PolicyPda,
+                vendor: null,
+                payoutMint: null,
+                shareMint: shareMint,
+                memberShareAccount: member1ShareAccount,
                 user: member1.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
             })
             .signers([member1])
@@ -320,11 +577,44 @@ describe("gadgetguard", () => {
         assert.equal(claimAccount.claimant.toBase58(), member1.publicKey.toBase58());
         assert.equal(claimAccount.requestedAmount.toString(), claimAmount.toString());
         assert.equal(claimAccount.evidenceUri, evidenceUri);
+        assert.deepEqual(claimAccount.evidenceHash, evidenceHash(evidenceUri));
         assert.deepEqual(claimAccount.status, { pending: {} });
         assert.equal(claimAccount.yesVotes, 0);
         assert.equal(claimAccount.noVotes, 0);
     });
 
+    it("Claimant appends evidence to a pending claim", async () => {
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([0, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+        const [evidencePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("evidence"), claimPda.toBuffer(), Buffer.from([0])],
+            program.programId
+        );
+        const uri = "https://evidence.example.com/claim1-repair-invoice";
+
+        await program.methods
+            .addEvidence(uri, evidenceHash(uri))
+            .accounts({
+                evidence: evidencePda,
+                claim: claimPda,
+                pool: poolPda,
+                user: member1.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member1])
+            .rpc();
+
+        const evidenceAccount = await program.account.evidence.fetch(evidencePda);
+        assert.equal(evidenceAccount.index, 0);
+        assert.equal(evidenceAccount.uri, uri);
+        assert.deepEqual(evidenceAccount.hash, evidenceHash(uri));
+
+        const claimAccount = await program.account.claim.fetch(claimPda);
+        assert.equal(claimAccount.evidenceCount, 1);
+    });
+
     it("Members vote on the claim", async () => {
         const [claimPda] = PublicKey.findProgramAddressSync(
             [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([0, 0, 0, 0, 0, 0, 0, 0])],
@@ -338,7 +628,10 @@ describe("gadgetguard", () => {
                 claim: claimPda,
                 member: member2Pda,
                 pool: poolPda,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
                 user: member2.publicKey,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member2])
             .rpc();
@@ -350,7 +643,10 @@ describe("gadgetguard", () => {
                 claim: claimPda,
                 member: member3Pda,
                 pool: poolPda,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
                 user: member3.publicKey,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member3])
             .rpc();
@@ -375,7 +671,10 @@ describe("gadgetguard", () => {
                     claim: claimPda,
                     member: member2Pda,
                     pool: poolPda,
+                    shareMint: shareMint,
+                    memberShareAccount: member2ShareAccount,
                     user: member2.publicKey,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
                 })
                 .signers([member2])
                 .rpc();
@@ -384,6 +683,66 @@ describe("gadgetguard", () => {
         } catch (err) {
             assert.include(err.toString(), "AlreadyVoted");
         }
+
+        try {
+            // Shares cannot be passed to another wallet to vote again
+            await transferChecked(
+                provider.connection,
+                admin.payer,
+                member2ShareAccount,
+                shareMint,
+                member1ShareAccount,
+                member2,
+                1,
+                6,
+                [],
+                undefined,
+                TOKEN_2022_PROGRAM_ID
+            );
+            assert.fail("Should have thrown error for transferring shares");
+        } catch (err) {
+            // Token-2022 NonTransferable error
+            assert.include(err.toString(), "0x25");
+        }
+
+        // Shares bought after the claim was submitted carry no vote on it
+        await program.methods
+            .deposit(new anchor.BN(1_000_000))
+            .accounts({
+                member: member1Pda,
+                pool: poolPda,
+                memberTokenAccount: member1TokenAccount,
+                poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: null,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member1ShareAccount,
+                user: member1.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member1])
+            .rpc();
+        try {
+            await program.methods
+                .voteClaim(true)
+                .accounts({
+                    claim: claimPda,
+                    member: member1Pda,
+                    pool: poolPda,
+                    shareMint: shareMint,
+                    memberShareAccount: member1ShareAccount,
+                    user: member1.publicKey,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([member1])
+                .rpc();
+
+            assert.fail("Should have thrown error for a vote without prior shares");
+        } catch (err) {
+            assert.include(err.toString(), "NoVotingWeight");
+        }
     });
 
     it("Finalizes and pays approved claim (after simulated time)", async () => {
@@ -411,17 +770,47 @@ describe("gadgetguard", () => {
                 .finalizeClaim()
                 .accounts({
                     claim: claimPda,
+                    policy: member1PolicyPda,
+                    member: member1Pda,
+                    pool: poolPda,
+                })
+                .rpc();
+
+            let claimAccount = await program.account.claim.fetch(claimPda);
+            assert.deepEqual(claimAccount.status, { approved: {} });
+
+            await program.methods
+                .executePayout()
+                .accounts({
+                    claim: claimPda,
+                    policy: member1PolicyPda,
+                    member: member1Pda,
                     pool: poolPda,
                     poolVault: poolVault,
-                    claimantTokenAccount: member1TokenAccount,
+                    payeeTokenAccount: member1TokenAccount,
+                    payoutMint: mint,
+                    treasury: null,
                     poolAuthority: poolAuthority,
-                    claimant: member1.publicKey,
+                    vendor: null,
+                    payoutSchedule: null,
+                    coverageMint: null,
+                    claimantCoverageAccount: null,
                     tokenProgram: TOKEN_PROGRAM_ID,
+                    token2022Program: null,
                 })
                 .rpc();
 
-            const claimAccount = await program.account.claim.fetch(claimPda);
+            claimAccount = await program.account.claim.fetch(claimPda);
             assert.deepEqual(claimAccount.status, { paid: {} });
+            assert.equal(
+                claimAccount.paidAmount.toString(),
+                claimAccount.requestedAmount.toString()
+            );
+            assert.equal(claimAccount.shortfallAmount.toString(), "0");
+
+            const member1Account = await program.account.member.fetch(member1Pda);
+            assert.equal(member1Account.totalClaimed.toString(), claimAccount.paidAmount.toString());
+            assert.equal(member1Account.claimsPaid, 1);
 
             const poolAccount = await program.account.pool.fetch(poolPda);
             assert.equal(
@@ -452,8 +841,8 @@ describe("gadgetguard", () => {
     });
 
     it("Rejects a claim with insufficient approval", async () => {
-        // Submit another claim
-        const claimAmount = 40_000_000;
+        // Submit another claim, leaving room in member2's limit while it stays pending
+        const claimAmount = 20_000_000;
         const [claimPda] = PublicKey.findProgramAddressSync(
             [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([1, 0, 0, 0, 0, 0, 0, 0])],
             program.programId
@@ -463,7 +852,8 @@ describe("gadgetguard", () => {
             .submitClaim(
                 { theft: {} },
                 new anchor.BN(claimAmount),
-                "https://evidence.example.com/claim2"
+                "https://evidence.example.com/claim2",
+                evidenceHash("https://evidence.example.com/claim2")
             )
             .accounts({
                 claim: claimPda,
@@ -471,7 +861,14 @@ describe("gadgetguard", () => {
                 pool: poolPda,
                 poolVault: poolVault,
                 poolAuthority: poolAuthority,
+                policy: member2PolicyPda,
+                vendor: null,
+                payoutMint: null,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
                 user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
             })
             .signers([member2])
@@ -484,7 +881,10 @@ describe("gadgetguard", () => {
                 claim: claimPda,
                 member: member1Pda,
                 pool: poolPda,
+                shareMint: shareMint,
+                memberShareAccount: member1ShareAccount,
                 user: member1.publicKey,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member1])
             .rpc();
@@ -496,7 +896,10 @@ describe("gadgetguard", () => {
                 claim: claimPda,
                 member: member3Pda,
                 pool: poolPda,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
                 user: member3.publicKey,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
             })
             .signers([member3])
             .rpc();
@@ -507,12 +910,9 @@ describe("gadgetguard", () => {
                 .finalizeClaim()
                 .accounts({
                     claim: claimPda,
+                    policy: member2PolicyPda,
+                    member: member2Pda,
                     pool: poolPda,
-                    poolVault: poolVault,
-                    claimantTokenAccount: member2TokenAccount,
-                    poolAuthority: poolAuthority,
-                    claimant: member2.publicKey,
-                    tokenProgram: TOKEN_PROGRAM_ID,
                 })
                 .rpc();
 
@@ -528,34 +928,2266 @@ describe("gadgetguard", () => {
         }
     });
 
-    it("Member withdraws tokens", async () => {
-        const withdrawAmount = 20_000_000; // 20 tokens
+    it("Keeps the voting rules a claim was submitted under", async () => {
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([0, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+        const before = await program.account.claim.fetch(claimPda);
+        assert.equal(before.voteDeadline.sub(before.createdTs).toNumber(), 86400);
 
-        const member3BalanceBefore = await getAccount(
-            provider.connection,
-            member3TokenAccount
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        const damageRules = poolAccount.claimTypeRules[0];
+        const configureDamage = (rules: typeof damageRules) =>
+            program.methods
+                .configureClaimType({ damage: {} }, rules)
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+        // Shortening the window and raising the bar leaves the pending claim untouched
+        await configureDamage({
+            ...damageRules,
+            voteWindowSecs: new anchor.BN(1),
+            approvalRatio: 10000,
+        });
+        const after = await program.account.claim.fetch(claimPda);
+        assert.equal(after.voteDeadline.toString(), before.voteDeadline.toString());
+        assert.equal(after.approvalRatio, before.approvalRatio);
+        assert.equal(after.quorum, before.quorum);
+
+        await configureDamage(damageRules);
+    });
+
+    it("Rejects claims for a disabled claim type", async () => {
+        await program.methods
+            .configureClaimType({ loss: {} }, {
+                enabled: false,
+                maxPayoutBps: 10000,
+                voteWindowSecs: new anchor.BN(86400),
+                quorum: 2,
+                approvalRatio: 6000,
+            })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        assert.equal(poolAccount.claimTypeRules[2].enabled, false);
+
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([2, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
         );
 
+        try {
+            await program.methods
+                .submitClaim(
+                    { loss: {} },
+                    new anchor.BN(10_000_000),
+                    "https://evidence.example.com/claim3",
+                    evidenceHash("https://evidence.example.com/claim3")
+                )
+                .accounts({
+                    claim: claimPda,
+                    member: member3Pda,
+                    pool: poolPda,
+                    poolVault: poolVault,
+                    poolAuthority: poolAuthority,
+                    policy: member3PolicyPda,
+                    vendor: null,
+                    payoutMint: null,
+                    shareMint: shareMint,
+                    memberShareAccount: member3ShareAccount,
+                    user: member3.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([member3])
+                .rpc();
+
+            assert.fail("Should have thrown error for disabled claim type");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidClaimType");
+        }
+    });
+
+    it("Configures installment payouts for large claims", async () => {
+        const threshold = 75_000_000; // 75 tokens
+        const tranches = 4;
+        const intervalSecs = 7 * 86400; // weekly
+
         await program.methods
-            .withdraw(new anchor.BN(withdrawAmount))
+            .configureInstallments(new anchor.BN(threshold), tranches, new anchor.BN(intervalSecs))
             .accounts({
-                member: member3Pda,
                 pool: poolPda,
-                memberTokenAccount: member3TokenAccount,
-                poolVault: poolVault,
-                poolAuthority: poolAuthority,
-                user: member3.publicKey,
-                tokenProgram: TOKEN_PROGRAM_ID,
+                admin: admin.publicKey,
             })
-            .signers([member3])
             .rpc();
 
-        const member3Account = await program.account.member.fetch(member3Pda);
-        assert.equal(
-            member3Account.depositedAmount.toString(),
-            (DEPOSIT_AMOUNT - withdrawAmount).toString()
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        assert.equal(poolAccount.installmentThreshold.toString(), threshold.toString());
+        assert.equal(poolAccount.installmentCount, tranches);
+        assert.equal(poolAccount.installmentIntervalSecs.toString(), intervalSecs.toString());
+
+        try {
+            // A single tranche is not an installment schedule
+            await program.methods
+                .configureInstallments(new anchor.BN(threshold), 1, new anchor.BN(intervalSecs))
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for invalid installment config");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidGovernanceConfig");
+        }
+    });
+
+    it("Submits a claim payable to a registered vendor", async () => {
+        const vendorWallet = Keypair.generate();
+        const [vendorPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vendor"), poolPda.toBuffer(), vendorWallet.publicKey.toBuffer()],
+            program.programId
         );
 
+        await program.methods
+            .registerVendor()
+            .accounts({
+                vendor: vendorPda,
+                pool: poolPda,
+                vendorWallet: vendorWallet.publicKey,
+                admin: admin.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const vendorAccount = await program.account.vendor.fetch(vendorPda);
+        assert.equal(vendorAccount.wallet.toBase58(), vendorWallet.publicKey.toBase58());
+        assert.equal(vendorAccount.active, true);
+
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([2, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+
+        await program.methods
+            .submitClaim(
+                { damage: {} },
+                new anchor.BN(10_000_000),
+                "https://evidence.example.com/repair-quote",
+                evidenceHash("https://evidence.example.com/repair-quote")
+            )
+            .accounts({
+                claim: claimPda,
+                member: member3Pda,
+                pool: poolPda,
+                poolVault: poolVault,
+                poolAuthority: poolAuthority,
+                policy: member3PolicyPda,
+                vendor: vendorPda,
+                payoutMint: null,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
+                user: member3.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member3])
+            .rpc();
+
+        const claimAccount = await program.account.claim.fetch(claimPda);
+        assert.equal(claimAccount.vendor.toBase58(), vendorPda.toBase58());
+
+        // The request is held against member3's claim limit and policy until it is decided and paid
+        const member3Account = await program.account.member.fetch(member3Pda);
+        assert.equal(member3Account.pendingClaims.toString(), "10000000");
+        const member3Policy = await program.account.policy.fetch(member3PolicyPda);
+        assert.equal(member3Policy.pendingAmount.toString(), "10000000");
+    });
+
+    it("Records an oracle decision that members can override", async () => {
+        const oracle = Keypair.generate();
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([2, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+
+        await program.methods
+            .configureOracle(oracle.publicKey, { oracleWithOverride: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        await program.methods
+            .oracleDecideClaim(true)
+            .accounts({
+                claim: claimPda,
+                policy: member3PolicyPda,
+                member: member3Pda,
+                pool: poolPda,
+                oracle: oracle.publicKey,
+            })
+            .signers([oracle])
+            .rpc();
+
+        const claimAccount = await program.account.claim.fetch(claimPda);
+        assert.equal(claimAccount.oracleDecision, true);
+        assert.deepEqual(claimAccount.status, { pending: {} });
+        // The oracle's amount is only applied if its decision stands at finalization
+        assert.equal(claimAccount.oracleAmount.toString(), claimAccount.requestedAmount.toString());
+
+        try {
+            // Only the configured oracle can decide
+            await program.methods
+                .oracleDecideClaim(false)
+                .accounts({
+                    claim: claimPda,
+                    policy: member3PolicyPda,
+                    member: member3Pda,
+                    pool: poolPda,
+                    oracle: member1.publicKey,
+                })
+                .signers([member1])
+                .rpc();
+
+            assert.fail("Should have thrown error for non-oracle signer");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidOracle");
+        }
+
+        // Restore member voting for the remaining tests
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Applies an Ed25519-signed assessment from a registered assessor", async () => {
+        const assessor = Keypair.generate();
+        const claimId = 3;
+        const requestedAmount = 20_000_000;
+        const approvedAmount = 15_000_000;
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([claimId, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+
+        // member1's claim limit for this period went to the first payout
+        await program.methods
+            .submitClaim(
+                { damage: {} },
+                new anchor.BN(requestedAmount),
+                "https://evidence.example.com/claim4",
+                evidenceHash("https://evidence.example.com/claim4")
+            )
+            .accounts({
+                claim: claimPda,
+                member: member2Pda,
+                pool: poolPda,
+                poolVault: poolVault,
+                poolAuthority: poolAuthority,
+                policy: member2PolicyPda,
+                vendor: null,
+                payoutMint: null,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
+                user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member2])
+            .rpc();
+
+        await program.methods
+            .configureAssessors([assessor.publicKey])
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        await program.methods
+            .configureOracle(PublicKey.default, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const assessment = {
+            pool: poolPda,
+            claimId: new anchor.BN(claimId),
+            approve: true,
+            approvedAmount: new anchor.BN(approvedAmount),
+            expiry: new anchor.BN(Math.floor(Date.now() / 1000) + 600),
+        };
+
+        // Borsh layout of `Assessment`: pool, claim_id, approve, approved_amount, expiry
+        const message = Buffer.concat([
+            assessment.pool.toBuffer(),
+            assessment.claimId.toArrayLike(Buffer, "le", 8),
+            Buffer.from([1]),
+            assessment.approvedAmount.toArrayLike(Buffer, "le", 8),
+            assessment.expiry.toArrayLike(Buffer, "le", 8),
+        ]);
+
+        await program.methods
+            .submitAssessment(assessment)
+            .accounts({
+                claim: claimPda,
+                policy: member2PolicyPda,
+                member: member2Pda,
+                pool: poolPda,
+                instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            })
+            .preInstructions([
+                Ed25519Program.createInstructionWithPrivateKey({
+                    privateKey: assessor.secretKey,
+                    message,
+                }),
+            ])
+            .rpc();
+
+        const claimAccount = await program.account.claim.fetch(claimPda);
+        assert.deepEqual(claimAccount.status, { approved: {} });
+        assert.equal(claimAccount.approvedAmount.toString(), approvedAmount.toString());
+
+        try {
+            // Without an oracle key, OracleOnly mode needs an assessor
+            await program.methods
+                .configureAssessors([])
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for removing the only decider");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidGovernanceConfig");
+        }
+
+        // Restore member voting for the remaining tests
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Committee decides a claim once the threshold attests", async () => {
+        const committeeMembers = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+        const [committeePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("committee"), poolPda.toBuffer()],
+            program.programId
+        );
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([4, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+
+        await program.methods
+            .createCommittee(committeeMembers.map((kp) => kp.publicKey), 2)
+            .accounts({
+                committee: committeePda,
+                pool: poolPda,
+                admin: admin.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        await program.methods
+            .submitClaim(
+                { theft: {} },
+                new anchor.BN(10_000_000),
+                "https://evidence.example.com/claim5",
+                evidenceHash("https://evidence.example.com/claim5")
+            )
+            .accounts({
+                claim: claimPda,
+                member: member2Pda,
+                pool: poolPda,
+                poolVault: poolVault,
+                poolAuthority: poolAuthority,
+                policy: member2PolicyPda,
+                vendor: null,
+                payoutMint: null,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
+                user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member2])
+            .rpc();
+
+        let poolAccount = await program.account.pool.fetch(poolPda);
+        assert.isTrue(poolAccount.committeeActive);
+
+        const attest = (attester: Keypair) =>
+            program.methods
+                .attestClaim(false)
+                .accounts({
+                    claim: claimPda,
+                    policy: member2PolicyPda,
+                    member: member2Pda,
+                    committee: committeePda,
+                    pool: poolPda,
+                    attester: attester.publicKey,
+                })
+                .signers([attester])
+                .rpc();
+
+        try {
+            // Member votes decide in VotesOnly mode, so the committee cannot
+            await attest(committeeMembers[0]);
+            assert.fail("Should have thrown error for attesting in VotesOnly mode");
+        } catch (err) {
+            assert.include(err.toString(), "OracleDisabled");
+        }
+
+        await program.methods
+            .configureOracle(PublicKey.default, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        for (const attester of committeeMembers.slice(0, 2)) {
+            await attest(attester);
+        }
+
+        const claimAccount = await program.account.claim.fetch(claimPda);
+        assert.deepEqual(claimAccount.status, { rejected: {} });
+
+        // Restore member voting and switch the committee off
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configureCommittee(false)
+            .accounts({
+                committee: committeePda,
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        poolAccount = await program.account.pool.fetch(poolPda);
+        assert.isFalse(poolAccount.committeeActive);
+
+        try {
+            await attest(committeeMembers[2]);
+            assert.fail("Should have thrown error for a disabled committee");
+        } catch (err) {
+            assert.include(err.toString(), "CommitteeDisabled");
+        }
+
+        try {
+            // Rotation needs two current members, one is not enough
+            await program.methods
+                .rotateCommittee([member1.publicKey], 1)
+                .accounts({
+                    committee: committeePda,
+                    pool: poolPda,
+                })
+                .remainingAccounts([
+                    { pubkey: committeeMembers[0].publicKey, isSigner: true, isWritable: false },
+                ])
+                .signers([committeeMembers[0]])
+                .rpc();
+
+            assert.fail("Should have thrown error for missing committee signatures");
+        } catch (err) {
+            assert.include(err.toString(), "CommitteeThresholdNotMet");
+        }
+    });
+
+    it("Requires paid-up premiums and a minimum streak to claim", async () => {
+        const premium = 10_000_000; // 10 tokens per month
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([5, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+        const configurePremiums = (amount: number, minMonths: number) =>
+            program.methods
+                .configurePremiums(new anchor.BN(amount), minMonths)
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+        try {
+            // Policyholders hold no shares to vote with, so member votes cannot decide premium claims
+            await configurePremiums(premium, 2);
+            assert.fail("Should have thrown error for premiums without an oracle decider");
+        } catch (err) {
+            assert.include(err.toString(), "PremiumsRequireDecider");
+        }
+
+        // The registered assessor decides claims
+        await program.methods
+            .configureOracle(PublicKey.default, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await configurePremiums(premium, 2);
+
+        try {
+            await program.methods
+                .configureOracle(PublicKey.default, { votesOnly: {} })
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+            assert.fail("Should have thrown error for member voting in a premium pool");
+        } catch (err) {
+            assert.include(err.toString(), "PremiumsRequireDecider");
+        }
+
+        // Premiums mint soulbound coverage credits, one per month
+        const [coverageMint] = PublicKey.findProgramAddressSync(
+            [Buffer.from("coverage_mint"), poolPda.toBuffer()],
+            program.programId
+        );
+        await program.methods
+            .createCoverageMint()
+            .accounts({
+                coverageMint: coverageMint,
+                pool: poolPda,
+                poolAuthority: poolAuthority,
+                admin: admin.publicKey,
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const member3CoverageAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            coverageMint,
+            member3.publicKey,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+
+        const payPremium = (months: number) =>
+            program.methods
+                .payPremium(months)
+                .accounts({
+                    member: member3Pda,
+                    pool: poolPda,
+                    memberTokenAccount: member3TokenAccount,
+                    poolVault: poolVault,
+                    acceptedMint: mint,
+                    treasury: null,
+                    backstop: null,
+                    poolAuthority: poolAuthority,
+                    coverageMint: coverageMint,
+                    memberCoverageAccount: member3CoverageAccount,
+                    user: member3.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    token2022Program: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([member3])
+                .rpc();
+
+        await payPremium(1);
+
+        try {
+            await program.methods
+                .submitClaim(
+                    { damage: {} },
+                    new anchor.BN(10_000_000),
+                    "https://evidence.example.com/claim6",
+                    evidenceHash("https://evidence.example.com/claim6")
+                )
+                .accounts({
+                    claim: claimPda,
+                    member: member3Pda,
+                    pool: poolPda,
+                    poolVault: poolVault,
+                    poolAuthority: poolAuthority,
+                    policy: member3PolicyPda,
+                    vendor: null,
+                    payoutMint: null,
+                    shareMint: shareMint,
+                    memberShareAccount: member3ShareAccount,
+                    user: member3.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([member3])
+                .rpc();
+
+            assert.fail("Should have thrown error for a one-month streak");
+        } catch (err) {
+            assert.include(err.toString(), "InsufficientPremiumStreak");
+        }
+
+        await payPremium(1);
+
+        const member3Account = await program.account.member.fetch(member3Pda);
+        assert.equal(member3Account.monthsPaidStreak, 2);
+        assert.equal(member3Account.premiumsPaid.toString(), (premium * 2).toString());
+        // Premiums are not refundable deposits
+        assert.equal(member3Account.depositedAmount.toString(), DEPOSIT_AMOUNT.toString());
+
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        assert.equal(poolAccount.totalPremiums.toString(), (premium * 2).toString());
+        assert.equal(poolAccount.coverageMint.toBase58(), coverageMint.toBase58());
+
+        const credits = await getAccount(
+            provider.connection,
+            member3CoverageAccount,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+        assert.equal(credits.amount.toString(), "2");
+
+        try {
+            // Credits only expire once the coverage they were minted for has lapsed
+            await program.methods
+                .expireCoverageCredits()
+                .accounts({
+                    member: member3Pda,
+                    pool: poolPda,
+                    coverageMint: coverageMint,
+                    memberCoverageAccount: member3CoverageAccount,
+                    poolAuthority: poolAuthority,
+                    token2022Program: TOKEN_2022_PROGRAM_ID,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for expiring active coverage");
+        } catch (err) {
+            assert.include(err.toString(), "CoverageStillActive");
+        }
+
+        try {
+            // Policyholders pay premiums; capital comes from underwriters while premiums are on
+            await program.methods
+                .deposit(new anchor.BN(1_000_000))
+                .accounts({
+                    member: member3Pda,
+                    pool: poolPda,
+                    memberTokenAccount: member3TokenAccount,
+                    poolVault: poolVault,
+                    acceptedMint: mint,
+                    treasury: null,
+                    poolAuthority: poolAuthority,
+                    shareMint: shareMint,
+                    memberShareAccount: member3ShareAccount,
+                    user: member3.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([member3])
+                .rpc();
+
+            assert.fail("Should have thrown error for a member deposit in a premium pool");
+        } catch (err) {
+            assert.include(err.toString(), "MemberDepositsDisabled");
+        }
+
+        // Switch premiums back off so later tests run against deposits and member votes
+        await configurePremiums(0, 0);
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Underwriters provide capital locked by outstanding exposure", async () => {
+        const underwriter = Keypair.generate();
+        const capital = 50_000_000; // 50 tokens
+
+        await provider.connection.confirmTransaction(
+            await provider.connection.requestAirdrop(underwriter.publicKey, LAMPORTS_PER_SOL)
+        );
+        const underwriterTokenAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            mint,
+            underwriter.publicKey
+        );
+        await mintTo(
+            provider.connection,
+            admin.payer,
+            mint,
+            underwriterTokenAccount,
+            admin.payer,
+            capital
+        );
+
+        const [underwriterPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("underwriter"), poolPda.toBuffer(), underwriter.publicKey.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .registerUnderwriter()
+            .accounts({
+                underwriter: underwriterPda,
+                pool: poolPda,
+                user: underwriter.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([underwriter])
+            .rpc();
+
+        const capitalAccounts = {
+            underwriter: underwriterPda,
+            pool: poolPda,
+            userTokenAccount: underwriterTokenAccount,
+            poolVault: poolVault,
+            acceptedMint: mint,
+            poolAuthority: poolAuthority,
+            user: underwriter.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+
+        await program.methods
+            .underwrite(new anchor.BN(capital))
+            .accounts(capitalAccounts)
+            .signers([underwriter])
+            .rpc();
+
+        const underwriterAccount = await program.account.underwriter.fetch(underwriterPda);
+        assert.equal(underwriterAccount.contributedAmount.toString(), capital.toString());
+        assert.isTrue(underwriterAccount.shares.gtn(0));
+
+        // Capital backing open claims cannot be withdrawn
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        const value = poolAccount.underwriterCapital.add(poolAccount.premiumIncome);
+        const freeCapital = value.sub(anchor.BN.min(value, poolAccount.outstandingExposure));
+
+        try {
+            await program.methods
+                .withdrawCapital(freeCapital.addn(1))
+                .accounts(capitalAccounts)
+                .signers([underwriter])
+                .rpc();
+
+            assert.fail("Should have thrown error for withdrawing locked capital");
+        } catch (err) {
+            assert.include(err.toString(), "UnderwriterCapitalLocked");
+        }
+    });
+
+    it("Members deposit an additional accepted asset at its rate", async () => {
+        // Second asset with 9 decimals, valued at half a base token per token
+        assetMint = await createMint(
+            provider.connection,
+            admin.payer,
+            admin.publicKey,
+            null,
+            9
+        );
+        assetVault = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            assetMint,
+            poolAuthority,
+            undefined,
+            TOKEN_PROGRAM_ID
+        );
+        member2AssetAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            assetMint,
+            member2.publicKey
+        );
+        const assetAmount = 10_000_000_000; // 10 tokens
+        await mintTo(
+            provider.connection,
+            admin.payer,
+            assetMint,
+            member2AssetAccount,
+            admin.payer,
+            assetAmount
+        );
+
+        await program.methods
+            .addAsset(5000)
+            .accounts({
+                pool: poolPda,
+                assetMint: assetMint,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const poolBefore = await program.account.pool.fetch(poolPda);
+        const memberBefore = await program.account.member.fetch(member2Pda);
+
+        await program.methods
+            .depositAsset(new anchor.BN(assetAmount))
+            .accounts({
+                member: member2Pda,
+                pool: poolPda,
+                assetMint: assetMint,
+                memberTokenAccount: member2AssetAccount,
+                assetVault: assetVault,
+                treasury: null,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
+                user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member2])
+            .rpc();
+
+        // 10 asset tokens at 0.5 are worth 5 base tokens
+        const value = 5_000_000;
+        const poolAfter = await program.account.pool.fetch(poolPda);
+        const memberAfter = await program.account.member.fetch(member2Pda);
+        assert.equal(poolAfter.assets.length, 1);
+        assert.equal(
+            poolAfter.totalDeposits.sub(poolBefore.totalDeposits).toString(),
+            value.toString()
+        );
+        assert.equal(
+            memberAfter.depositedAmount.sub(memberBefore.depositedAmount).toString(),
+            value.toString()
+        );
+
+        // Shares can be redeemed for the asset too: 1 base token buys back 2 asset tokens
+        const redeemed = 1_000_000;
+        await program.methods
+            .withdrawAsset(new anchor.BN(redeemed))
+            .accounts({
+                member: member2Pda,
+                pool: poolPda,
+                assetMint: assetMint,
+                memberTokenAccount: member2AssetAccount,
+                assetVault: assetVault,
+                poolVault: poolVault,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
+                user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                baseTokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member2])
+            .rpc();
+
+        const vault = await getAccount(provider.connection, assetVault);
+        assert.equal(vault.amount.toString(), (assetAmount - 2_000_000_000).toString());
+        const poolRedeemed = await program.account.pool.fetch(poolPda);
+        assert.equal(
+            poolAfter.totalDeposits.sub(poolRedeemed.totalDeposits).toString(),
+            redeemed.toString()
+        );
+    });
+
+    it("Routes deposit fees to the treasury and lets the admin withdraw them", async () => {
+        const [treasury] = PublicKey.findProgramAddressSync(
+            [Buffer.from("treasury"), poolPda.toBuffer(), mint.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .createTreasury()
+            .accounts({
+                treasury: treasury,
+                mint: mint,
+                pool: poolPda,
+                poolAuthority: poolAuthority,
+                admin: admin.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // 1% deposit fee
+        await program.methods
+            .configureFees(100, 0, 0)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const depositAmount = 10_000_000; // 10 tokens
+        const fee = 100_000;
+        const poolBefore = await program.account.pool.fetch(poolPda);
+
+        await program.methods
+            .deposit(new anchor.BN(depositAmount))
+            .accounts({
+                member: member1Pda,
+                pool: poolPda,
+                memberTokenAccount: member1TokenAccount,
+                poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: treasury,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member1ShareAccount,
+                user: member1.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member1])
+            .rpc();
+
+        // The fee stays out of member balances
+        const poolAfter = await program.account.pool.fetch(poolPda);
+        assert.equal(
+            poolAfter.totalDeposits.sub(poolBefore.totalDeposits).toString(),
+            (depositAmount - fee).toString()
+        );
+        assert.equal(poolAfter.feesCollected.toString(), fee.toString());
+        const treasuryAccount = await getAccount(provider.connection, treasury);
+        assert.equal(treasuryAccount.amount.toString(), fee.toString());
+
+        const adminTokenAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            mint,
+            admin.publicKey
+        );
+        await program.methods
+            .withdrawFees(new anchor.BN(fee))
+            .accounts({
+                treasury: treasury,
+                mint: mint,
+                destination: adminTokenAccount,
+                pool: poolPda,
+                poolAuthority: poolAuthority,
+                admin: admin.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+
+        const adminAccount = await getAccount(provider.connection, adminTokenAccount);
+        assert.equal(adminAccount.amount.toString(), fee.toString());
+
+        await program.methods
+            .configureFees(0, 0, 0)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Deploys idle capital to a lending strategy and recalls it with yield", async () => {
+        const lending = anchor.workspace.MockLending as Program<MockLending>;
+        const [reserve] = PublicKey.findProgramAddressSync(
+            [Buffer.from("reserve"), mint.toBuffer()],
+            lending.programId
+        );
+        const [lendingAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("lending_authority"), mint.toBuffer()],
+            lending.programId
+        );
+        // The strategy only ever sees an escrow owned by its own authority, never the pool vault
+        const [strategyAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("strategy_authority"), poolPda.toBuffer()],
+            program.programId
+        );
+        const strategyEscrow = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            mint,
+            strategyAuthority,
+            undefined,
+            TOKEN_PROGRAM_ID
+        );
+        const [position] = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), mint.toBuffer(), strategyAuthority.toBuffer()],
+            lending.programId
+        );
+
+        await lending.methods
+            .initializeReserve()
+            .accounts({
+                reserve: reserve,
+                mint: mint,
+                lendingAuthority: lendingAuthority,
+                payer: admin.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+        await lending.methods
+            .openPosition()
+            .accounts({
+                position: position,
+                mint: mint,
+                owner: strategyAuthority,
+                payer: admin.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        // Keep at least 80% of vault funds liquid
+        await program.methods
+            .configureStrategy(lending.programId, 8000)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const strategyAccounts = {
+            pool: poolPda,
+            poolVault: poolVault,
+            acceptedMint: mint,
+            poolAuthority: poolAuthority,
+            strategyAuthority: strategyAuthority,
+            strategyEscrow: strategyEscrow,
+            strategyProgram: lending.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+        const depositAccounts = [
+            { pubkey: position, isSigner: false, isWritable: true },
+            { pubkey: reserve, isSigner: false, isWritable: true },
+        ];
+        // Deployments re-check the reserve, so the asset vaults come before the strategy's accounts
+        const deployAccounts = [
+            { pubkey: assetVault, isSigner: false, isWritable: false },
+            ...depositAccounts,
+        ];
+
+        const vaultBefore = await getAccount(provider.connection, poolVault);
+        const vaultAmount = Number(vaultBefore.amount);
+
+        try {
+            await program.methods
+                .deployToStrategy(new anchor.BN(Math.floor(vaultAmount / 5) + 1))
+                .accounts({ ...strategyAccounts, admin: admin.publicKey })
+                .remainingAccounts(deployAccounts)
+                .rpc();
+
+            assert.fail("Should have thrown error for breaching the liquid reserve");
+        } catch (err) {
+            assert.include(err.toString(), "LiquidReserveBreached");
+        }
+
+        const deployed = Math.floor(vaultAmount / 10);
+        await program.methods
+            .deployToStrategy(new anchor.BN(deployed))
+            .accounts({ ...strategyAccounts, admin: admin.publicKey })
+            .remainingAccounts(deployAccounts)
+            .rpc();
+
+        let poolAccount = await program.account.pool.fetch(poolPda);
+        assert.equal(poolAccount.strategyDeployed.toString(), deployed.toString());
+        let escrow = await getAccount(provider.connection, strategyEscrow);
+        assert.equal(escrow.amount.toString(), "0");
+        const depositsBefore = poolAccount.totalDeposits;
+
+        // The lending market pays interest on the position
+        const interest = 1_000_000;
+        await lending.methods
+            .accrueInterest(new anchor.BN(interest))
+            .accounts({
+                funderTokenAccount: member1TokenAccount,
+                mint: mint,
+                funder: member1.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                position: position,
+                reserve: reserve,
+            })
+            .signers([member1])
+            .rpc();
+
+        const withdrawAccounts = [
+            ...depositAccounts,
+            { pubkey: lendingAuthority, isSigner: false, isWritable: false },
+        ];
+        try {
+            // Recalls pass the strategy's accounts through, so only the admin can make them
+            await program.methods
+                .recallFromStrategy(new anchor.BN(deployed + interest))
+                .accounts({ ...strategyAccounts, admin: member1.publicKey })
+                .remainingAccounts(withdrawAccounts)
+                .signers([member1])
+                .rpc();
+
+            assert.fail("Should have thrown error for a recall by a non-admin");
+        } catch (err) {
+            assert.include(err.toString(), "Unauthorized");
+        }
+
+        await program.methods
+            .recallFromStrategy(new anchor.BN(deployed + interest))
+            .accounts({ ...strategyAccounts, admin: admin.publicKey })
+            .remainingAccounts(withdrawAccounts)
+            .rpc();
+
+        poolAccount = await program.account.pool.fetch(poolPda);
+        assert.equal(poolAccount.strategyDeployed.toString(), "0");
+        assert.equal(
+            poolAccount.totalDeposits.sub(depositsBefore).toString(),
+            interest.toString()
+        );
+        const vaultAfter = await getAccount(provider.connection, poolVault);
+        assert.equal(Number(vaultAfter.amount), vaultAmount + interest);
+        escrow = await getAccount(provider.connection, strategyEscrow);
+        assert.equal(escrow.amount.toString(), "0");
+
+        // Funds the strategy cannot return are written off so the strategy can be changed
+        const lost = 1_000_000;
+        await program.methods
+            .deployToStrategy(new anchor.BN(lost))
+            .accounts({ ...strategyAccounts, admin: admin.publicKey })
+            .remainingAccounts(deployAccounts)
+            .rpc();
+        try {
+            await program.methods
+                .configureStrategy(PublicKey.default, 10000)
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for funds still deployed");
+        } catch (err) {
+            assert.include(err.toString(), "StrategyFundsDeployed");
+        }
+
+        try {
+            await program.methods
+                .recognizeStrategyLoss(new anchor.BN(lost + 1))
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for a loss above the deployed funds");
+        } catch (err) {
+            assert.include(err.toString(), "StrategyLossExceedsDeployed");
+        }
+
+        poolAccount = await program.account.pool.fetch(poolPda);
+        const valueBefore = poolAccount.totalDeposits
+            .add(poolAccount.underwriterCapital)
+            .add(poolAccount.premiumIncome);
+        await program.methods
+            .recognizeStrategyLoss(new anchor.BN(lost))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        poolAccount = await program.account.pool.fetch(poolPda);
+        const valueAfter = poolAccount.totalDeposits
+            .add(poolAccount.underwriterCapital)
+            .add(poolAccount.premiumIncome);
+        assert.equal(poolAccount.strategyDeployed.toString(), "0");
+        assert.equal(valueBefore.sub(valueAfter).toString(), lost.toString());
+
+        await program.methods
+            .configureStrategy(PublicKey.default, 10000)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Funds a backstop vault from reinsurers and premium shares", async () => {
+        const [backstop] = PublicKey.findProgramAddressSync(
+            [Buffer.from("backstop"), poolPda.toBuffer()],
+            program.programId
+        );
+
+        await program.methods
+            .createBackstop()
+            .accounts({
+                backstop: backstop,
+                acceptedMint: mint,
+                pool: poolPda,
+                poolAuthority: poolAuthority,
+                admin: admin.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+        const capital = 5_000_000; // 5 tokens
+        await program.methods
+            .fundBackstop(new anchor.BN(capital))
+            .accounts({
+                pool: poolPda,
+                backstop: backstop,
+                funderTokenAccount: member1TokenAccount,
+                acceptedMint: mint,
+                funder: member1.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([member1])
+            .rpc();
+
+        // 20% of each premium goes to the backstop
+        const premium = 1_000_000;
+        await program.methods
+            .configureOracle(PublicKey.default, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configurePremiums(new anchor.BN(premium), 0)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configureBackstop(2000)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const [coverageMint] = PublicKey.findProgramAddressSync(
+            [Buffer.from("coverage_mint"), poolPda.toBuffer()],
+            program.programId
+        );
+        await program.methods
+            .payPremium(1)
+            .accounts({
+                member: member3Pda,
+                pool: poolPda,
+                memberTokenAccount: member3TokenAccount,
+                poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: null,
+                backstop: backstop,
+                poolAuthority: poolAuthority,
+                coverageMint: coverageMint,
+                memberCoverageAccount: await getAssociatedTokenAddress(
+                    coverageMint,
+                    member3.publicKey,
+                    false,
+                    TOKEN_2022_PROGRAM_ID
+                ),
+                user: member3.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member3])
+            .rpc();
+
+        const backstopAccount = await getAccount(provider.connection, backstop);
+        assert.equal(backstopAccount.amount.toString(), (capital + premium / 5).toString());
+
+        // Only claims still owed a shortfall can draw on the backstop
+        const [paidClaimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), Buffer.from([0, 0, 0, 0, 0, 0, 0, 0])],
+            program.programId
+        );
+        try {
+            await program.methods
+                .drawBackstop()
+                .accounts({
+                    claim: paidClaimPda,
+                    policy: member1PolicyPda,
+                    member: member1Pda,
+                    pool: poolPda,
+                    backstop: backstop,
+                    payeeTokenAccount: member1TokenAccount,
+                    acceptedMint: mint,
+                    poolAuthority: poolAuthority,
+                    vendor: null,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for a claim without shortfall");
+        } catch (err) {
+            assert.include(err.toString(), "ClaimNotPaying");
+        }
+
+        await program.methods
+            .configureBackstop(0)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configurePremiums(new anchor.BN(0), 0)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Counts every vault towards the minimum reserve ratio", async () => {
+        try {
+            await program.methods
+                .configureReserve(10_001)
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+
+            assert.fail("Should have thrown error for a reserve above 100%");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidReserveConfig");
+        }
+
+        // Require reserves covering all claim limits
+        await program.methods
+            .configureReserve(10_000)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const withdraw = (assetVaults: PublicKey[]) =>
+            program.methods
+                .withdraw(new anchor.BN(1_000_000))
+                .accounts({
+                    member: member3Pda,
+                    pool: poolPda,
+                    memberTokenAccount: member3TokenAccount,
+                    poolVault: poolVault,
+                    acceptedMint: mint,
+                    poolAuthority: poolAuthority,
+                    shareMint: shareMint,
+                    memberShareAccount: member3ShareAccount,
+                    user: member3.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts(
+                    assetVaults.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+                )
+                .signers([member3])
+                .rpc();
+
+        try {
+            // Only the pool authority's vault for each accepted asset counts
+            await withdraw([member2AssetAccount]);
+            assert.fail("Should have thrown error for a foreign asset vault");
+        } catch (err) {
+            assert.include(err.toString(), "InvalidAssetVault");
+        }
+
+        const poolBefore = await program.account.pool.fetch(poolPda);
+        await withdraw([assetVault]);
+        const poolAfter = await program.account.pool.fetch(poolPda);
+        assert.equal(poolBefore.totalDeposits.sub(poolAfter.totalDeposits).toString(), "1000000");
+
+        await program.methods
+            .configureReserve(0)
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Enforces the minimum deposit, per-member maximum and pool capacity", async () => {
+        const configureLimits = (min: number, perMember: anchor.BN, capacity: anchor.BN) =>
+            program.methods
+                .configureDepositLimits(new anchor.BN(min), perMember, capacity)
+                .accounts({
+                    pool: poolPda,
+                    admin: admin.publicKey,
+                })
+                .rpc();
+        const deposit = (amount: number) =>
+            program.methods
+                .deposit(new anchor.BN(amount))
+                .accounts({
+                    member: member1Pda,
+                    pool: poolPda,
+                    memberTokenAccount: member1TokenAccount,
+                    poolVault: poolVault,
+                    acceptedMint: mint,
+                    treasury: null,
+                    poolAuthority: poolAuthority,
+                    shareMint: shareMint,
+                    memberShareAccount: member1ShareAccount,
+                    user: member1.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([member1])
+                .rpc();
+        const expectError = async (amount: number, error: string) => {
+            try {
+                await deposit(amount);
+                assert.fail(`Should have thrown ${error}`);
+            } catch (err) {
+                assert.include(err.toString(), error);
+            }
+        };
+
+        // The per-member cap applies to the value of the member's shares
+        const poolBefore = await program.account.pool.fetch(poolPda);
+        const shareAccount = await getShareAccount(member1ShareAccount);
+        const shareMintAccount = await getShareMint();
+        const held = new anchor.BN(shareAccount.amount.toString())
+            .mul(poolBefore.totalDeposits)
+            .div(new anchor.BN(shareMintAccount.supply.toString()));
+        await configureLimits(2_000_000, held.addn(1_000_000), new anchor.BN(0));
+        await expectError(1_000_000, "DepositBelowMinimum");
+        await expectError(2_000_000, "MemberDepositCapExceeded");
+
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        await configureLimits(2_000_000, new anchor.BN(0), poolAccount.totalDeposits.addn(1_000_000));
+        await expectError(2_000_000, "PoolCapacityExceeded");
+
+        await configureLimits(0, new anchor.BN(0), new anchor.BN(0));
+    });
+
+    it("Vests new deposits into the claim limit over the waiting period", async () => {
+        await program.methods
+            .configureClaimVesting(new anchor.BN(86400))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const depositAmount = 20_000_000; // 20 tokens
+        await program.methods
+            .deposit(new anchor.BN(depositAmount))
+            .accounts({
+                member: member2Pda,
+                pool: poolPda,
+                memberTokenAccount: member2TokenAccount,
+                poolVault: poolVault,
+                acceptedMint: mint,
+                treasury: null,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
+                user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member2])
+            .rpc();
+
+        const member2Account = await program.account.member.fetch(member2Pda);
+        assert.equal(member2Account.vestingAmount.toString(), depositAmount.toString());
+        assert.equal(member2Account.vestingEndTs.sub(member2Account.vestingStartTs).toNumber(), 86400);
+
+        // A claim only the unvested deposit would cover is rejected
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        const supply = new anchor.BN(
+            (await getShareMint()).supply.toString()
+        );
+        const shares = new anchor.BN(
+            (await getShareAccount(member2ShareAccount)).amount.toString()
+        );
+        const shareValue = shares.mul(poolAccount.totalDeposits).div(supply);
+        const requested = shareValue
+            .subn(depositAmount / 2)
+            .muln(poolAccount.maxClaimPct)
+            .divn(10000);
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), poolAccount.claimCount.toArrayLike(Buffer, "le", 8)],
+            program.programId
+        );
+
+        try {
+            await program.methods
+                .submitClaim(
+                    { damage: {} },
+                    requested,
+                    "https://evidence.example.com/vesting",
+                    evidenceHash("https://evidence.example.com/vesting")
+                )
+                .accounts({
+                    claim: claimPda,
+                    member: member2Pda,
+                    pool: poolPda,
+                    poolVault: poolVault,
+                    poolAuthority: poolAuthority,
+                    policy: member2PolicyPda,
+                    vendor: null,
+                    payoutMint: null,
+                    shareMint: shareMint,
+                    memberShareAccount: member2ShareAccount,
+                    user: member2.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([member2])
+                .rpc();
+
+            assert.fail("Should have thrown error for a claim against unvested deposits");
+        } catch (err) {
+            assert.include(err.toString(), "ClaimExceedsLimit");
+        }
+
+        await program.methods
+            .configureClaimVesting(new anchor.BN(0))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Caps payouts per claim period and over a member's lifetime", async () => {
+        const submit = (
+            member: Keypair,
+            memberPda: PublicKey,
+            policy: PublicKey,
+            shareAccount: PublicKey,
+            claimPda: PublicKey
+        ) =>
+            program.methods
+                .submitClaim(
+                    { damage: {} },
+                    new anchor.BN(1_000_000),
+                    "https://evidence.example.com/caps",
+                    evidenceHash("https://evidence.example.com/caps")
+                )
+                .accounts({
+                    claim: claimPda,
+                    member: memberPda,
+                    pool: poolPda,
+                    poolVault: poolVault,
+                    poolAuthority: poolAuthority,
+                    policy: policy,
+                    vendor: null,
+                    payoutMint: null,
+                    shareMint: shareMint,
+                    memberShareAccount: shareAccount,
+                    user: member.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([member])
+                .rpc();
+
+        const poolAccount = await program.account.pool.fetch(poolPda);
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), poolAccount.claimCount.toArrayLike(Buffer, "le", 8)],
+            program.programId
+        );
+
+        // member1's first claim, paid or still pending, used up their limit for the current period
+        try {
+            await submit(member1, member1Pda, member1PolicyPda, member1ShareAccount, claimPda);
+            assert.fail("Should have thrown error for a used-up period limit");
+        } catch (err) {
+            assert.include(err.toString(), "ClaimExceedsLimit");
+        }
+
+        // A lifetime cap below the requested amount rejects the claim outright
+        await program.methods
+            .configureClaimCaps(poolAccount.claimPeriodSecs, new anchor.BN(1))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        try {
+            await submit(member3, member3Pda, member3PolicyPda, member3ShareAccount, claimPda);
+            assert.fail("Should have thrown error for exceeding the lifetime cap");
+        } catch (err) {
+            assert.include(err.toString(), "LifetimeCapExceeded");
+        }
+
+        await program.methods
+            .configureClaimCaps(poolAccount.claimPeriodSecs, new anchor.BN(0))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Admits only allowlisted wallets to a gated pool", async () => {
+        const allowed = Keypair.generate();
+        const other = Keypair.generate();
+        const outsider = Keypair.generate();
+        for (const wallet of [allowed, outsider]) {
+            await provider.connection.confirmTransaction(
+                await provider.connection.requestAirdrop(wallet.publicKey, LAMPORTS_PER_SOL)
+            );
+        }
+
+        // Two-leaf Merkle tree over SHA-256 wallet hashes, pairs hashed in sorted order
+        const leaf = (wallet: Keypair) =>
+            createHash("sha256").update(wallet.publicKey.toBuffer()).digest();
+        const [left, right] = [leaf(allowed), leaf(other)].sort(Buffer.compare);
+        const root = createHash("sha256").update(Buffer.concat([left, right])).digest();
+
+        await program.methods
+            .configureAllowlist(Array.from(root))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const join = (wallet: Keypair, proof: Buffer[]) => {
+            const [memberPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("member"), poolPda.toBuffer(), wallet.publicKey.toBuffer()],
+                program.programId
+            );
+            return program.methods
+                .joinPool(proof.map((node) => Array.from(node)))
+                .accounts({
+                    member: memberPda,
+                    pool: poolPda,
+                    user: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([wallet])
+                .rpc();
+        };
+
+        try {
+            await join(outsider, [leaf(other)]);
+            assert.fail("Should have thrown error for a wallet off the allowlist");
+        } catch (err) {
+            assert.include(err.toString(), "NotAllowlisted");
+        }
+
+        const poolBefore = await program.account.pool.fetch(poolPda);
+        await join(allowed, [leaf(other)]);
+        const poolAfter = await program.account.pool.fetch(poolPda);
+        assert.equal(poolAfter.memberCount.toNumber(), poolBefore.memberCount.toNumber() + 1);
+
+        await program.methods
+            .configureAllowlist(Array(32).fill(0))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Pays large claims in installments from a payout schedule", async () => {
+        const oracle = Keypair.generate();
+        const claimAmount = 8_000_000; // 8 tokens
+        const tranches = 4;
+        const poolBefore = await program.account.pool.fetch(poolPda);
+
+        await program.methods
+            .configureInstallments(new anchor.BN(5_000_000), tranches, new anchor.BN(7 * 86400))
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configureOracle(oracle.publicKey, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), poolBefore.claimCount.toArrayLike(Buffer, "le", 8)],
+            program.programId
+        );
+        const [schedulePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("payout_schedule"), claimPda.toBuffer()],
+            program.programId
+        );
+        const [coverageMint] = PublicKey.findProgramAddressSync(
+            [Buffer.from("coverage_mint"), poolPda.toBuffer()],
+            program.programId
+        );
+        const member3CoverageAccount = getAssociatedTokenAddressSync(
+            coverageMint,
+            member3.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
+        );
+        const creditsBefore = await getAccount(
+            provider.connection,
+            member3CoverageAccount,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+
+        await program.methods
+            .submitClaim(
+                { damage: {} },
+                new anchor.BN(claimAmount),
+                "https://evidence.example.com/installments",
+                evidenceHash("https://evidence.example.com/installments")
+            )
+            .accounts({
+                claim: claimPda,
+                member: member3Pda,
+                pool: poolPda,
+                poolVault: poolVault,
+                poolAuthority: poolAuthority,
+                policy: member3PolicyPda,
+                vendor: null,
+                payoutMint: null,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
+                user: member3.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member3])
+            .rpc();
+        await program.methods
+            .oracleDecideClaim(true)
+            .accounts({
+                claim: claimPda,
+                policy: member3PolicyPda,
+                member: member3Pda,
+                pool: poolPda,
+                oracle: oracle.publicKey,
+            })
+            .signers([oracle])
+            .rpc();
+
+        const payoutAccounts = {
+            claim: claimPda,
+            policy: member3PolicyPda,
+            member: member3Pda,
+            pool: poolPda,
+            poolVault: poolVault,
+            payeeTokenAccount: member3TokenAccount,
+            payoutMint: mint,
+            treasury: null,
+            poolAuthority: poolAuthority,
+            vendor: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+        const executePayout = () =>
+            program.methods
+                .executePayout()
+                .accounts({
+                    ...payoutAccounts,
+                    payoutSchedule: schedulePda,
+                    // Premiums are off, so the payout doesn't use a coverage credit
+                    coverageMint: null,
+                    claimantCoverageAccount: null,
+                    token2022Program: null,
+                })
+                .rpc();
+
+        try {
+            // The schedule has to exist before the payout starts
+            await executePayout();
+            assert.fail("Should have thrown error for a missing payout schedule");
+        } catch (err) {
+            assert.include(err.toString(), "PayoutScheduleRequired");
+        }
+
+        await program.methods
+            .createPayoutSchedule()
+            .accounts({
+                payoutSchedule: schedulePda,
+                claim: claimPda,
+                pool: poolPda,
+                payer: admin.publicKey,
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+        await executePayout();
+
+        let claimAccount = await program.account.claim.fetch(claimPda);
+        assert.deepEqual(claimAccount.status, { paying: {} });
+        const creditsAfter = await getAccount(
+            provider.connection,
+            member3CoverageAccount,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+        );
+        assert.equal(creditsAfter.amount.toString(), creditsBefore.amount.toString());
+
+        const balanceBefore = await getAccount(provider.connection, member3TokenAccount);
+        const releasePayout = () =>
+            program.methods
+                .releasePayout()
+                .accounts({ ...payoutAccounts, payoutSchedule: schedulePda })
+                .rpc();
+        await releasePayout();
+
+        // The first tranche is released straight away, the rest weekly
+        const tranche = claimAmount / tranches;
+        const balanceAfter = await getAccount(provider.connection, member3TokenAccount);
+        assert.equal(Number(balanceAfter.amount) - Number(balanceBefore.amount), tranche);
+        claimAccount = await program.account.claim.fetch(claimPda);
+        assert.deepEqual(claimAccount.status, { paying: {} });
+        assert.equal(claimAccount.paidAmount.toString(), tranche.toString());
+        const schedule = await program.account.payoutSchedule.fetch(schedulePda);
+        assert.equal(schedule.tranchesReleased, 1);
+
+        try {
+            await releasePayout();
+            assert.fail("Should have thrown error for a tranche not yet due");
+        } catch (err) {
+            assert.include(err.toString(), "NoTrancheDue");
+        }
+
+        await program.methods
+            .configureInstallments(
+                poolBefore.installmentThreshold,
+                poolBefore.installmentCount,
+                poolBefore.installmentIntervalSecs
+            )
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Draws on the backstop to pay off a claim's shortfall", async () => {
+        const oracle = Keypair.generate();
+        const claimAmount = 3_000_000; // 3 tokens, paid in the asset
+        const poolBefore = await program.account.pool.fetch(poolPda);
+
+        await program.methods
+            .configureOracle(oracle.publicKey, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), poolBefore.claimCount.toArrayLike(Buffer, "le", 8)],
+            program.programId
+        );
+        const [backstop] = PublicKey.findProgramAddressSync(
+            [Buffer.from("backstop"), poolPda.toBuffer()],
+            program.programId
+        );
+        const [coverageMint] = PublicKey.findProgramAddressSync(
+            [Buffer.from("coverage_mint"), poolPda.toBuffer()],
+            program.programId
+        );
+        const member3AssetAccount = await createAssociatedTokenAccount(
+            provider.connection,
+            admin.payer,
+            assetMint,
+            member3.publicKey
+        );
+
+        await program.methods
+            .submitClaim(
+                { damage: {} },
+                new anchor.BN(claimAmount),
+                "https://evidence.example.com/shortfall",
+                evidenceHash("https://evidence.example.com/shortfall")
+            )
+            .accounts({
+                claim: claimPda,
+                member: member3Pda,
+                pool: poolPda,
+                poolVault: assetVault,
+                poolAuthority: poolAuthority,
+                policy: member3PolicyPda,
+                vendor: null,
+                payoutMint: assetMint,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
+                user: member3.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member3])
+            .rpc();
+        await program.methods
+            .oracleDecideClaim(true)
+            .accounts({
+                claim: claimPda,
+                policy: member3PolicyPda,
+                member: member3Pda,
+                pool: poolPda,
+                oracle: oracle.publicKey,
+            })
+            .signers([oracle])
+            .rpc();
+
+        // member2 redeems most of the asset before the payout, leaving 2 asset tokens worth 1 base token
+        await program.methods
+            .withdrawAsset(new anchor.BN(3_000_000))
+            .accounts({
+                member: member2Pda,
+                pool: poolPda,
+                assetMint: assetMint,
+                memberTokenAccount: member2AssetAccount,
+                assetVault: assetVault,
+                poolVault: poolVault,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member2ShareAccount,
+                user: member2.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                baseTokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member2])
+            .rpc();
+        const available = 1_000_000;
+
+        await program.methods
+            .executePayout()
+            .accounts({
+                claim: claimPda,
+                policy: member3PolicyPda,
+                member: member3Pda,
+                pool: poolPda,
+                poolVault: assetVault,
+                payeeTokenAccount: member3AssetAccount,
+                payoutMint: assetMint,
+                treasury: null,
+                poolAuthority: poolAuthority,
+                vendor: null,
+                payoutSchedule: null,
+                coverageMint: coverageMint,
+                claimantCoverageAccount: getAssociatedTokenAddressSync(
+                    coverageMint,
+                    member3.publicKey,
+                    false,
+                    TOKEN_2022_PROGRAM_ID
+                ),
+                tokenProgram: TOKEN_PROGRAM_ID,
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .rpc();
+
+        // The vault pays what it holds and the rest is owed as a shortfall
+        let claimAccount = await program.account.claim.fetch(claimPda);
+        let poolAccount = await program.account.pool.fetch(poolPda);
+        assert.deepEqual(claimAccount.status, { paying: {} });
+        assert.equal(claimAccount.paidAmount.toString(), available.toString());
+        assert.equal(claimAccount.shortfallAmount.toString(), (claimAmount - available).toString());
+        assert.equal(
+            poolAccount.totalShortfall.sub(poolBefore.totalShortfall).toString(),
+            (claimAmount - available).toString()
+        );
+
+        // The backstop pays the shortfall in the primary mint
+        const backstopBefore = await getAccount(provider.connection, backstop);
+        const balanceBefore = await getAccount(provider.connection, member3TokenAccount);
+        await program.methods
+            .drawBackstop()
+            .accounts({
+                claim: claimPda,
+                policy: member3PolicyPda,
+                member: member3Pda,
+                pool: poolPda,
+                backstop: backstop,
+                payeeTokenAccount: member3TokenAccount,
+                acceptedMint: mint,
+                poolAuthority: poolAuthority,
+                vendor: null,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+
+        const backstopAfter = await getAccount(provider.connection, backstop);
+        const balanceAfter = await getAccount(provider.connection, member3TokenAccount);
+        claimAccount = await program.account.claim.fetch(claimPda);
+        poolAccount = await program.account.pool.fetch(poolPda);
+        assert.deepEqual(claimAccount.status, { paid: {} });
+        assert.equal(claimAccount.paidAmount.toString(), claimAmount.toString());
+        assert.equal(claimAccount.shortfallAmount.toString(), "0");
+        assert.equal(poolAccount.totalShortfall.toString(), poolBefore.totalShortfall.toString());
+        assert.equal(
+            poolAccount.totalBackstopDraws.sub(poolBefore.totalBackstopDraws).toString(),
+            (claimAmount - available).toString()
+        );
+        assert.equal(
+            Number(backstopBefore.amount) - Number(backstopAfter.amount),
+            claimAmount - available
+        );
+        assert.equal(
+            Number(balanceAfter.amount) - Number(balanceBefore.amount),
+            claimAmount - available
+        );
+
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Pays a short vault pro-rata and settles the shortfall once refilled", async () => {
+        const oracle = Keypair.generate();
+        const claimAmount = 2_000_000; // 2 tokens, paid in the asset
+        const poolBefore = await program.account.pool.fetch(poolPda);
+
+        await program.methods
+            .configureOracle(oracle.publicKey, { oracleOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+
+        const [claimPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("claim"), poolPda.toBuffer(), poolBefore.claimCount.toArrayLike(Buffer, "le", 8)],
+            program.programId
+        );
+        const [coverageMint] = PublicKey.findProgramAddressSync(
+            [Buffer.from("coverage_mint"), poolPda.toBuffer()],
+            program.programId
+        );
+        const member3AssetAccount = getAssociatedTokenAddressSync(assetMint, member3.publicKey);
+        const assetAccounts = {
+            member: member2Pda,
+            pool: poolPda,
+            assetMint: assetMint,
+            memberTokenAccount: member2AssetAccount,
+            assetVault: assetVault,
+            poolAuthority: poolAuthority,
+            shareMint: shareMint,
+            memberShareAccount: member2ShareAccount,
+            user: member2.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+        };
+        const depositAsset = (amount: number) =>
+            program.methods
+                .depositAsset(new anchor.BN(amount))
+                .accounts({ ...assetAccounts, treasury: null })
+                .signers([member2])
+                .rpc();
+        const withdrawAsset = (value: number) =>
+            program.methods
+                .withdrawAsset(new anchor.BN(value))
+                .accounts({ ...assetAccounts, poolVault: poolVault, baseTokenProgram: TOKEN_PROGRAM_ID })
+                .signers([member2])
+                .rpc();
+
+        // 4 asset tokens back the claim when it is submitted
+        await depositAsset(4_000_000_000);
+        await program.methods
+            .submitClaim(
+                { damage: {} },
+                new anchor.BN(claimAmount),
+                "https://evidence.example.com/settle",
+                evidenceHash("https://evidence.example.com/settle")
+            )
+            .accounts({
+                claim: claimPda,
+                member: member3Pda,
+                pool: poolPda,
+                poolVault: assetVault,
+                poolAuthority: poolAuthority,
+                policy: member3PolicyPda,
+                vendor: null,
+                payoutMint: assetMint,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
+                user: member3.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([member3])
+            .rpc();
+        await program.methods
+            .oracleDecideClaim(true)
+            .accounts({
+                claim: claimPda,
+                policy: member3PolicyPda,
+                member: member3Pda,
+                pool: poolPda,
+                oracle: oracle.publicKey,
+            })
+            .signers([oracle])
+            .rpc();
+
+        // Draining the vault to 1 asset token leaves a quarter of the claim payable
+        await withdrawAsset(1_500_000);
+        const available = 500_000;
+
+        const payoutAccounts = {
+            claim: claimPda,
+            policy: member3PolicyPda,
+            member: member3Pda,
+            pool: poolPda,
+            poolVault: assetVault,
+            payeeTokenAccount: member3AssetAccount,
+            payoutMint: assetMint,
+            treasury: null,
+            poolAuthority: poolAuthority,
+            vendor: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+        const payeeBefore = await getAccount(provider.connection, member3AssetAccount);
+        await program.methods
+            .executePayout()
+            .accounts({
+                ...payoutAccounts,
+                payoutSchedule: null,
+                coverageMint: coverageMint,
+                claimantCoverageAccount: getAssociatedTokenAddressSync(
+                    coverageMint,
+                    member3.publicKey,
+                    false,
+                    TOKEN_2022_PROGRAM_ID
+                ),
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+            })
+            .rpc();
+
+        let claimAccount = await program.account.claim.fetch(claimPda);
+        let poolAccount = await program.account.pool.fetch(poolPda);
+        let payeeAfter = await getAccount(provider.connection, member3AssetAccount);
+        assert.deepEqual(claimAccount.status, { paying: {} });
+        assert.equal(claimAccount.paidAmount.toString(), available.toString());
+        assert.equal(claimAccount.shortfallAmount.toString(), (claimAmount - available).toString());
+        assert.equal(
+            poolAccount.totalShortfall.sub(poolBefore.totalShortfall).toString(),
+            (claimAmount - available).toString()
+        );
+        assert.equal(Number(payeeAfter.amount) - Number(payeeBefore.amount), 1_000_000_000);
+
+        try {
+            // Withdrawals are paused while the pool owes a shortfall
+            await withdrawAsset(100_000);
+            assert.fail("Should have thrown error for a pool in shortfall");
+        } catch (err) {
+            assert.include(err.toString(), "PoolInShortfall");
+        }
+
+        // Fresh deposits refill the vault and the shortfall is settled in full
+        await depositAsset(4_000_000_000);
+        await program.methods
+            .settleShortfall()
+            .accounts(payoutAccounts)
+            .rpc();
+
+        claimAccount = await program.account.claim.fetch(claimPda);
+        poolAccount = await program.account.pool.fetch(poolPda);
+        payeeAfter = await getAccount(provider.connection, member3AssetAccount);
+        assert.deepEqual(claimAccount.status, { paid: {} });
+        assert.equal(claimAccount.paidAmount.toString(), claimAmount.toString());
+        assert.equal(claimAccount.shortfallAmount.toString(), "0");
+        assert.equal(poolAccount.totalShortfall.toString(), poolBefore.totalShortfall.toString());
+        assert.equal(Number(payeeAfter.amount) - Number(payeeBefore.amount), 4_000_000_000);
+
+        await program.methods
+            .configureOracle(PublicKey.default, { votesOnly: {} })
+            .accounts({
+                pool: poolPda,
+                admin: admin.publicKey,
+            })
+            .rpc();
+    });
+
+    it("Member withdraws tokens", async () => {
+        const withdrawAmount = 20_000_000; // 20 tokens
+
+        const member3BalanceBefore = await getAccount(
+            provider.connection,
+            member3TokenAccount
+        );
+        const poolBefore = await program.account.pool.fetch(poolPda);
+        const supplyBefore = new anchor.BN(
+            (await getShareMint()).supply.toString()
+        );
+        const sharesBefore = new anchor.BN(
+            (await getShareAccount(member3ShareAccount)).amount.toString()
+        );
+
+        await program.methods
+            .withdraw(new anchor.BN(withdrawAmount))
+            .accounts({
+                member: member3Pda,
+                pool: poolPda,
+                memberTokenAccount: member3TokenAccount,
+                poolVault: poolVault,
+                acceptedMint: mint,
+                poolAuthority: poolAuthority,
+                shareMint: shareMint,
+                memberShareAccount: member3ShareAccount,
+                user: member3.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([member3])
+            .rpc();
+
+        const member3Account = await program.account.member.fetch(member3Pda);
+        assert.equal(
+            member3Account.depositedAmount.toString(),
+            (DEPOSIT_AMOUNT - withdrawAmount).toString()
+        );
+
+        // Shares are burned at the current share price, rounded up
+        const burned = new anchor.BN(withdrawAmount)
+            .mul(supplyBefore)
+            .add(poolBefore.totalDeposits.subn(1))
+            .div(poolBefore.totalDeposits);
+        const sharesAfter = await getShareAccount(member3ShareAccount);
+        assert.equal(sharesAfter.amount.toString(), sharesBefore.sub(burned).toString());
+
         const member3BalanceAfter = await getAccount(
             provider.connection,
             member3TokenAccount